@@ -1,13 +1,16 @@
 use crate::{blit::BlitPipeline, upscaling::ViewUpscalingPipeline};
 use bevy_ecs::{prelude::*, query::QueryItem};
+use bevy_math::{UVec2, Vec2};
 use bevy_render::{
-    camera::{CameraOutputMode, ClearColor, ClearColorConfig, ExtractedCamera},
+    camera::{
+        CameraOutputMode, ClearColor, ClearColorConfig, ExtractedCamera, NormalizedRenderTarget,
+    },
     render_graph::{NodeRunError, RenderGraphContext, ViewNode},
     render_resource::{
         BindGroup, BindGroupEntries, PipelineCache, RenderPassDescriptor, TextureViewId,
     },
     renderer::RenderContext,
-    view::ViewTarget,
+    view::{ExtractedWindows, ViewTarget},
 };
 use std::sync::Mutex;
 
@@ -85,8 +88,11 @@ impl ViewNode for UpscalingNode {
 
         if let Some(camera) = camera {
             if let Some(viewport) = &camera.viewport {
-                let size = viewport.physical_size;
-                let position = viewport.physical_position;
+                // Windows clamped to `Window::max_surface_size` are rendered smaller than their
+                // swapchain, so the viewport is scaled to the upscaled output.
+                let scale = output_scale(camera, world);
+                let size = (viewport.physical_size.as_vec2() * scale).as_uvec2();
+                let position = (viewport.physical_position.as_vec2() * scale).as_uvec2();
                 render_pass.set_scissor_rect(position.x, position.y, size.x, size.y);
             }
         }
@@ -98,3 +104,19 @@ impl ViewNode for UpscalingNode {
         Ok(())
     }
 }
+
+/// Returns how much larger the output of the camera is than its render target.
+fn output_scale(camera: &ExtractedCamera, world: &World) -> Vec2 {
+    let (Some(NormalizedRenderTarget::Window(window_ref)), Some(target_size)) =
+        (&camera.target, camera.physical_target_size)
+    else {
+        return Vec2::ONE;
+    };
+    world
+        .get_resource::<ExtractedWindows>()
+        .and_then(|windows| windows.get(&window_ref.entity()))
+        .map_or(Vec2::ONE, |window| {
+            UVec2::new(window.physical_width, window.physical_height).as_vec2()
+                / target_size.max(UVec2::ONE).as_vec2()
+        })
+}
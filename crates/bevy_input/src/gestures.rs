@@ -1,6 +1,6 @@
 //! Gestures functionality, from touchscreens and touchpads.
 
-use bevy_ecs::event::Event;
+use bevy_ecs::{entity::Entity, event::Event};
 use bevy_math::Vec2;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
@@ -8,6 +8,8 @@ use bevy_reflect::Reflect;
 #[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
+use crate::touch::TouchPhase;
+
 /// Two-finger pinch gesture, often used for magnifications.
 ///
 /// Positive delta values indicate magnification (zooming in) and
@@ -72,3 +74,52 @@ pub struct DoubleTapGesture;
     reflect(Serialize, Deserialize)
 )]
 pub struct PanGesture(pub Vec2);
+
+/// A touchpad or touchscreen gesture on a window, together with its phase.
+///
+/// This is sent alongside [`PinchGesture`], [`RotationGesture`], [`DoubleTapGesture`] and
+/// [`PanGesture`], but also reports which window the gesture happened on and whether it has
+/// started, changed or ended, so deltas can be accumulated per gesture. Gestures are distinct from
+/// [`MouseWheel`](crate::mouse::MouseWheel) events, so a trackpad pinch can be told apart from
+/// zooming with Ctrl and the scroll wheel.
+///
+/// ## Platform-specific
+///
+/// - Only available on **`macOS`** and **`iOS`**, except for pan gestures.
+/// - On **`iOS`**, must be enabled first
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GestureInput {
+    /// Window the gesture happened on.
+    pub window: Entity,
+    /// The gesture and its delta since the previous event of the same gesture.
+    pub gesture: Gesture,
+    /// The phase of the gesture.
+    ///
+    /// A [`Gesture::DoubleTap`] is reported once, with [`TouchPhase::Ended`].
+    pub phase: TouchPhase,
+}
+
+/// A gesture reported by [`GestureInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum Gesture {
+    /// A two-finger pinch, see [`PinchGesture`].
+    Pinch(f32),
+    /// A two-finger rotation, see [`RotationGesture`].
+    Rotation(f32),
+    /// A double tap, see [`DoubleTapGesture`].
+    DoubleTap,
+    /// A pan, see [`PanGesture`].
+    Pan(Vec2),
+}
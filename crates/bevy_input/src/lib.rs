@@ -104,6 +104,7 @@ impl Plugin for InputPlugin {
             .add_event::<RotationGesture>()
             .add_event::<DoubleTapGesture>()
             .add_event::<PanGesture>()
+            .add_event::<GestureInput>()
             // gamepad
             .add_event::<GamepadEvent>()
             .add_event::<GamepadConnectionEvent>()
@@ -139,6 +140,7 @@ impl Plugin for InputPlugin {
                 .register_type::<RotationGesture>()
                 .register_type::<DoubleTapGesture>()
                 .register_type::<PanGesture>()
+                .register_type::<GestureInput>()
                 .register_type::<TouchInput>()
                 .register_type::<RawGamepadEvent>()
                 .register_type::<RawGamepadAxisChangedEvent>()
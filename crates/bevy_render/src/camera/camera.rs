@@ -40,7 +40,7 @@ use bevy_render_macros::ExtractComponent;
 use bevy_transform::components::{GlobalTransform, Transform};
 use bevy_window::{
     NormalizedWindowRef, PrimaryWindow, Window, WindowCreated, WindowRef, WindowResized,
-    WindowScaleFactorChanged,
+    WindowScaleFactorChanged, WindowSurfaceNeedsReconfigure,
 };
 use core::ops::Range;
 use derive_more::derive::From;
@@ -848,9 +848,16 @@ impl NormalizedRenderTarget {
             NormalizedRenderTarget::Window(window_ref) => resolutions
                 .into_iter()
                 .find(|(entity, _)| *entity == window_ref.entity())
-                .map(|(_, window)| RenderTargetInfo {
-                    physical_size: window.physical_size(),
-                    scale_factor: window.resolution.scale_factor(),
+                .map(|(_, window)| {
+                    // Cameras render at the surface size, which `max_surface_size` can clamp,
+                    // while keeping the logical size of the window.
+                    let surface_size = window.surface_size();
+                    let surface_scale =
+                        surface_size.x as f32 / window.physical_width().max(1) as f32;
+                    RenderTargetInfo {
+                        physical_size: surface_size,
+                        scale_factor: window.resolution.scale_factor() * surface_scale,
+                    }
                 }),
             NormalizedRenderTarget::Image(image_target) => {
                 let image = images.get(&image_target.handle)?;
@@ -888,8 +895,8 @@ impl NormalizedRenderTarget {
 
 /// System in charge of updating a [`Camera`] when its window or projection changes.
 ///
-/// The system detects window creation, resize, and scale factor change events to update the camera
-/// [`Projection`] if needed.
+/// The system detects window creation, resize, surface reconfiguration and scale factor change
+/// events to update the camera [`Projection`] if needed.
 ///
 /// ## World Resources
 ///
@@ -902,6 +909,7 @@ pub fn camera_system(
     mut window_resized_events: EventReader<WindowResized>,
     mut window_created_events: EventReader<WindowCreated>,
     mut window_scale_factor_changed_events: EventReader<WindowScaleFactorChanged>,
+    mut window_surface_events: EventReader<WindowSurfaceNeedsReconfigure>,
     mut image_asset_events: EventReader<AssetEvent<Image>>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
     windows: Query<(Entity, &Window)>,
@@ -914,6 +922,7 @@ pub fn camera_system(
     let mut changed_window_ids = <HashSet<_>>::default();
     changed_window_ids.extend(window_created_events.read().map(|event| event.window));
     changed_window_ids.extend(window_resized_events.read().map(|event| event.window));
+    changed_window_ids.extend(window_surface_events.read().map(|event| event.window));
     let scale_factor_changed_window_ids: HashSet<_> = window_scale_factor_changed_events
         .read()
         .map(|event| event.window)
@@ -1011,6 +1020,60 @@ pub fn camera_system(
     }
 }
 
+/// Marker component for a [`Camera`] whose viewport was set by [`letterbox_cameras`].
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct Letterboxed;
+
+/// System that gives cameras rendering to a window with a
+/// [`Window::fullscreen_aspect_lock`] the letterboxed viewport of the window.
+///
+/// Cameras with a viewport of their own are left alone. The viewport is removed again once the
+/// window leaves fullscreen or loses its aspect lock.
+pub fn letterbox_cameras(
+    mut commands: Commands,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    mut cameras: Query<(Entity, &mut Camera, Has<Letterboxed>)>,
+) {
+    let primary_window = primary_window.iter().next();
+    for (entity, mut camera, letterboxed) in &mut cameras {
+        let letterbox = match camera.target.normalize(primary_window) {
+            Some(NormalizedRenderTarget::Window(window)) => windows
+                .get(window.entity())
+                .ok()
+                .and_then(Window::letterbox),
+            _ => None,
+        };
+        match letterbox {
+            Some(rect) if letterboxed || camera.viewport.is_none() => {
+                let depth = camera
+                    .viewport
+                    .as_ref()
+                    .map_or(0.0..1.0, |viewport| viewport.depth.clone());
+                let is_current = camera.viewport.as_ref().is_some_and(|viewport| {
+                    viewport.physical_position == rect.min && viewport.physical_size == rect.size()
+                });
+                if !is_current {
+                    camera.viewport = Some(Viewport {
+                        physical_position: rect.min,
+                        physical_size: rect.size(),
+                        depth,
+                    });
+                }
+                if !letterboxed {
+                    commands.entity(entity).insert(Letterboxed);
+                }
+            }
+            None if letterboxed => {
+                camera.viewport = None;
+                commands.entity(entity).remove::<Letterboxed>();
+            }
+            _ => {}
+        }
+    }
+}
+
 /// This component lets you control the [`TextureUsages`] field of the main texture generated for the camera
 #[derive(Component, ExtractComponent, Clone, Copy, Reflect)]
 #[reflect(opaque)]
@@ -1,8 +1,13 @@
-use crate::extract_resource::ExtractResource;
-use bevy_color::Color;
+use crate::{
+    camera::{Camera, NormalizedRenderTarget},
+    extract_resource::ExtractResource,
+};
+use bevy_color::{Alpha, Color};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
+use bevy_utils::once;
+use bevy_window::{PrimaryWindow, TransparentWindow};
 use derive_more::derive::From;
 use serde::{Deserialize, Serialize};
 
@@ -35,3 +40,41 @@ impl Default for ClearColor {
         Self(Color::srgb_u8(43, 44, 47))
     }
 }
+
+/// Warns once if an active camera clears a [`TransparentWindow`] to an opaque color, which hides
+/// the transparency of the window.
+pub fn warn_opaque_clear_of_transparent_windows(
+    cameras: Query<&Camera>,
+    clear_color: Res<ClearColor>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    transparent_windows: Query<(), With<TransparentWindow>>,
+) {
+    if transparent_windows.is_empty() {
+        return;
+    }
+    let primary_window = primary_window.iter().next();
+    for camera in &cameras {
+        if !camera.is_active {
+            continue;
+        }
+        let Some(NormalizedRenderTarget::Window(window)) = camera.target.normalize(primary_window)
+        else {
+            continue;
+        };
+        if !transparent_windows.contains(window.entity()) {
+            continue;
+        }
+        let color = match camera.clear_color {
+            ClearColorConfig::Default => clear_color.0,
+            ClearColorConfig::Custom(color) => color,
+            ClearColorConfig::None => continue,
+        };
+        if color.alpha() >= 1.0 {
+            once!(tracing::warn!(
+                "A camera clears the transparent window {} to an opaque color, so the window won't \
+                 be transparent. Use a clear color with zero alpha, e.g. `Color::NONE`.",
+                window.entity()
+            ));
+        }
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     extract_component::ExtractComponentPlugin, extract_resource::ExtractResourcePlugin,
     render_graph::RenderGraph, ExtractSchedule, Render, RenderApp, RenderSet,
 };
-use bevy_app::{App, Plugin};
+use bevy_app::{App, Plugin, PostUpdate};
 use bevy_ecs::schedule::IntoSystemConfigs;
 
 #[derive(Default)]
@@ -29,6 +29,7 @@ impl Plugin for CameraPlugin {
             .register_type::<Exposure>()
             .register_type::<TemporalJitter>()
             .register_type::<MipBias>()
+            .register_type::<Letterboxed>()
             .init_resource::<ManualTextureViews>()
             .init_resource::<ClearColor>()
             .add_plugins((
@@ -36,7 +37,14 @@ impl Plugin for CameraPlugin {
                 ExtractResourcePlugin::<ManualTextureViews>::default(),
                 ExtractResourcePlugin::<ClearColor>::default(),
                 ExtractComponentPlugin::<CameraMainTextureUsages>::default(),
-            ));
+            ))
+            .add_systems(
+                PostUpdate,
+                (
+                    letterbox_cameras.before(CameraUpdateSystem),
+                    warn_opaque_clear_of_transparent_windows,
+                ),
+            );
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
@@ -20,6 +20,7 @@ use alloc::sync::Arc;
 use bevy_ecs::{prelude::*, system::SystemState};
 use bevy_platform_support::time::Instant;
 use bevy_time::TimeSender;
+use bevy_window::WindowPresentation;
 use wgpu::{
     Adapter, AdapterInfo, CommandBuffer, CommandEncoder, DeviceType, Instance, Queue,
     RequestAdapterOptions,
@@ -85,14 +86,20 @@ pub fn render_system(world: &mut World, state: &mut SystemState<Query<Entity, Wi
             world.entity_mut(view_entity).remove::<ViewTarget>();
         }
 
+        let presentation = world.get_resource::<WindowPresentation>().cloned();
         let mut windows = world.resource_mut::<ExtractedWindows>();
         for window in windows.values_mut() {
             if let Some(surface_texture) = window.swap_chain_texture.take() {
-                // TODO(clean): winit docs recommends calling pre_present_notify before this.
-                // though `present()` doesn't present the frame, it schedules it to be presented
+                // Lets the windowing backend call winit's `pre_present_notify`.
+                // Though `present()` doesn't present the frame, it schedules it to be presented
                 // by wgpu.
-                // https://docs.rs/winit/0.29.9/wasm32-unknown-unknown/winit/window/struct.Window.html#method.pre_present_notify
+                if let Some(presentation) = &presentation {
+                    presentation.pre_present(window.entity);
+                }
                 surface_texture.present();
+                if let Some(presentation) = &presentation {
+                    presentation.presented(window.entity);
+                }
             }
         }
 
@@ -9,6 +9,7 @@ use bevy_platform_support::collections::HashSet;
 use bevy_utils::default;
 use bevy_window::{
     CompositeAlphaMode, PresentMode, PrimaryWindow, RawHandleWrapper, Window, WindowClosing,
+    WindowPresentation,
 };
 use core::{
     num::NonZero,
@@ -29,7 +30,12 @@ impl Plugin for WindowRenderPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ScreenshotPlugin);
 
+        let presentation = app.world().get_resource::<WindowPresentation>().cloned();
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            if let Some(presentation) = presentation {
+                presentation.attach_renderer();
+                render_app.insert_resource(presentation);
+            }
             render_app
                 .init_resource::<ExtractedWindows>()
                 .init_resource::<WindowSurfaces>()
@@ -115,18 +121,27 @@ fn extract_windows(
             extracted_windows.primary = Some(entity);
         }
 
+        // The swapchain keeps the full size of the window, cameras rendering at a clamped
+        // `Window::surface_size` are upscaled to it.
         let (new_width, new_height) = (
             window.resolution.physical_width().max(1),
             window.resolution.physical_height().max(1),
         );
 
+        let present_mode = window
+            .desired_present_latency
+            .present_mode(window.present_mode);
+        let desired_maximum_frame_latency = window
+            .desired_present_latency
+            .maximum_frame_latency(window.desired_maximum_frame_latency);
+
         let extracted_window = extracted_windows.entry(entity).or_insert(ExtractedWindow {
             entity,
             handle: handle.clone(),
             physical_width: new_width,
             physical_height: new_height,
-            present_mode: window.present_mode,
-            desired_maximum_frame_latency: window.desired_maximum_frame_latency,
+            present_mode,
+            desired_maximum_frame_latency,
             swap_chain_texture: None,
             swap_chain_texture_view: None,
             size_changed: false,
@@ -139,8 +154,8 @@ fn extract_windows(
         extracted_window.swap_chain_texture_view = None;
         extracted_window.size_changed = new_width != extracted_window.physical_width
             || new_height != extracted_window.physical_height;
-        extracted_window.present_mode_changed =
-            window.present_mode != extracted_window.present_mode;
+        extracted_window.present_mode_changed = present_mode != extracted_window.present_mode
+            || desired_maximum_frame_latency != extracted_window.desired_maximum_frame_latency;
 
         if extracted_window.size_changed {
             debug!(
@@ -157,9 +172,10 @@ fn extract_windows(
         if extracted_window.present_mode_changed {
             debug!(
                 "Window Present Mode changed from {:?} to {:?}",
-                extracted_window.present_mode, window.present_mode
+                extracted_window.present_mode, present_mode
             );
-            extracted_window.present_mode = window.present_mode;
+            extracted_window.present_mode = present_mode;
+            extracted_window.desired_maximum_frame_latency = desired_maximum_frame_latency;
         }
     }
 
@@ -400,6 +416,10 @@ pub fn create_surfaces(
                 PresentMode::AutoVsync => wgpu::PresentMode::AutoVsync,
                 PresentMode::AutoNoVsync => wgpu::PresentMode::AutoNoVsync,
             };
+            data.configuration.desired_maximum_frame_latency = window
+                .desired_maximum_frame_latency
+                .map(NonZero::<u32>::get)
+                .unwrap_or(DEFAULT_DESIRED_MAXIMUM_FRAME_LATENCY);
             render_device.configure_surface(&data.surface, &data.configuration);
         }
 
@@ -27,7 +27,7 @@ use bevy_platform_support::collections::HashSet;
 use bevy_reflect::Reflect;
 use bevy_tasks::AsyncComputeTaskPool;
 use bevy_utils::default;
-use bevy_window::{PrimaryWindow, WindowRef};
+use bevy_window::{PrimaryWindow, WindowRef, WindowScreenshotRequested};
 use core::ops::Deref;
 use std::{
     path::Path,
@@ -189,6 +189,19 @@ fn clear_screenshots(mut commands: Commands, screenshots: Query<Entity, With<Cap
     }
 }
 
+/// Spawns a [`Screenshot`] for each [`WindowScreenshotRequested`] event.
+pub fn handle_window_screenshot_requests(
+    mut commands: Commands,
+    mut requests: EventReader<WindowScreenshotRequested>,
+) {
+    for request in requests.read() {
+        let mut screenshot = commands.spawn(Screenshot::window(request.window));
+        if let Some(path) = &request.path {
+            screenshot.observe(save_to_disk(path.clone()));
+        }
+    }
+}
+
 pub fn trigger_screenshots(
     mut commands: Commands,
     captured_screenshots: ResMut<CapturedScreenshots>,
@@ -403,7 +416,12 @@ impl Plugin for ScreenshotPlugin {
                 .after(event_update_system)
                 .before(ApplyDeferred),
         )
-        .add_systems(Update, trigger_screenshots)
+        .add_systems(
+            Update,
+            (handle_window_screenshot_requests, trigger_screenshots),
+        )
+        // Also added by `WindowPlugin`, but rendering may run without it.
+        .add_event::<WindowScreenshotRequested>()
         .register_type::<Screenshot>()
         .register_type::<ScreenshotCaptured>();
 
@@ -6,7 +6,7 @@ use bevy_input::{
     mouse::{MouseButtonInput, MouseMotion, MouseWheel},
     touch::TouchInput,
 };
-use bevy_math::{IVec2, Vec2};
+use bevy_math::{IVec2, UVec2, Vec2};
 
 #[cfg(feature = "std")]
 use std::path::PathBuf;
@@ -20,7 +20,7 @@ use bevy_reflect::Reflect;
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
-use crate::WindowTheme;
+use crate::{CursorGrabMode, WindowTheme};
 
 /// A window event that is sent whenever a window's logical size has changed.
 #[derive(Event, Debug, Clone, PartialEq)]
@@ -194,6 +194,26 @@ pub struct CursorLeft {
     pub window: Entity,
 }
 
+/// Raw, unaccelerated mouse motion while a window with [`Window::raw_mouse_motion`] is
+/// focused.
+///
+/// Unlike [`MouseMotion`](bevy_input::mouse::MouseMotion), this is tagged with the focused window
+/// and only sent for windows that opted in. Unlike [`CursorMoved`], it is sent regardless of the
+/// cursor's grab mode, and even when the cursor is at the edge of the screen.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct RawMouseMotion {
+    /// Window that was focused when the mouse moved.
+    pub window: Entity,
+    /// The change in the position of the pointing device since the last event was sent.
+    pub delta: Vec2,
+}
+
 /// An Input Method Editor event.
 ///
 /// This event is the translated version of the `WindowEvent::Ime` from the `winit` crate.
@@ -277,6 +297,89 @@ pub struct WindowOccluded {
     pub occluded: bool,
 }
 
+/// An event that is sent when the physical size of a window or of its rendering surface has
+/// actually changed.
+///
+/// Unlike [`WindowResized`], this is not sent for resizes that keep the physical size, e.g.
+/// repeated resize events with the same size during a drag-resize, so renderers can
+/// reconfigure their swapchain exactly once per change. It is also sent when the rendering
+/// surface is clamped to a different [`Window::max_surface_size`].
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowSurfaceNeedsReconfigure {
+    /// Window whose surface has changed size.
+    pub window: Entity,
+    /// The new physical size of the window, which its swapchain is configured at.
+    pub physical_size: UVec2,
+    /// The new size the window is rendered at in physical pixels before it is upscaled to
+    /// `physical_size`, see [`Window::surface_size`].
+    pub surface_size: UVec2,
+}
+
+/// An event that is sent for every window when the app is suspended and its rendering surfaces
+/// must no longer be used.
+///
+/// Renderers should stop presenting to the window and release its swapchain until
+/// [`WindowSurfaceRestored`] is sent for it.
+///
+/// ## Platform-specific
+///
+/// - **Android:** The native window is destroyed, so presenting to its surface may crash.
+/// - **Others:** The surface stays valid, but the app isn't visible until it is resumed.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowSurfaceLost {
+    /// Window whose surface was lost.
+    pub window: Entity,
+}
+
+/// An event that is sent for every window when the app is resumed after a
+/// [`WindowSurfaceLost`], once the window can be rendered to again.
+///
+/// On Android, the window has a new [`RawHandleWrapper`](crate::RawHandleWrapper) by the time
+/// this is sent, so renderers should create a new surface for it.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowSurfaceRestored {
+    /// Window whose surface was restored.
+    pub window: Entity,
+}
+
+/// An event that is sent once per window, when the windowing system has first reported the
+/// size it actually granted the window.
+///
+/// The size requested in [`Window::resolution`](crate::Window::resolution) may not be honored,
+/// e.g. on Wayland the compositor decides the size in its first configure event. Renderers can
+/// wait for this event to allocate their framebuffers at the authoritative size.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowReady {
+    /// Window that is ready.
+    pub window: Entity,
+    /// The physical size granted to the window.
+    pub physical_size: UVec2,
+}
+
 /// An event that indicates a window's scale factor has changed.
 #[derive(Event, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
@@ -339,6 +442,45 @@ pub enum FileDragAndDrop {
     },
 }
 
+/// An event that is sent while a file is dragged over a window, before it is dropped, e.g. to
+/// highlight a drop target and validate the file type.
+///
+/// Sent alongside [`FileDragAndDrop::HoveredFile`], once per hovered file.
+#[derive(Event, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredFile {
+    /// Window the file is hovered over.
+    pub window: Entity,
+    /// Path to the file that might be dropped in.
+    pub path: PathBuf,
+    /// The last known cursor position over the window, in logical pixels.
+    ///
+    /// The platform doesn't report where a file is hovered, and cursor movement is usually not
+    /// reported during a drag either, so this is `None` if the cursor hasn't been seen over the
+    /// window yet.
+    pub position: Option<Vec2>,
+}
+
+/// An event that is sent when files stop being dragged over a window without being dropped.
+///
+/// Sent alongside [`FileDragAndDrop::HoveredFileCanceled`].
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HoveredFileCancelled {
+    /// Window that had its file hover canceled.
+    pub window: Entity,
+}
+
 /// An event that is sent when a window is repositioned in physical pixels.
 #[derive(Event, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
@@ -354,6 +496,160 @@ pub struct WindowMoved {
     pub position: IVec2,
 }
 
+/// An event that is sent when a window has moved onto a different monitor, e.g. to save the
+/// window layout or reconfigure rendering for the new monitor.
+///
+/// Unlike [`WindowMoved`], this is sent at most once per window and frame, and only when the
+/// monitor the window is on has changed.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowMovedToMonitor {
+    /// Window that moved.
+    pub window: Entity,
+    /// The [`Monitor`](crate::Monitor) entity the window is now on.
+    pub monitor: Entity,
+}
+
+/// An event that is sent when a window has been activated or deactivated, along with the likely
+/// reason, e.g. to fully pause a minimized game but only mute it when the user switched apps.
+///
+/// Unlike [`WindowFocused`], this is sent at most once per window and frame, after the focus,
+/// minimize and occlusion signals of that frame have been combined. Focus that is lost and
+/// regained within a frame isn't reported.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowActivationChanged {
+    /// Window that was activated or deactivated.
+    pub window: Entity,
+    /// Whether the window is now active, i.e. focused.
+    pub active: bool,
+    /// Why the window was activated or deactivated.
+    pub reason: ActivationReason,
+}
+
+/// Why a window was activated or deactivated, see [`WindowActivationChanged`].
+///
+/// The reason is inferred from the state of the window when its focus changed, since the
+/// platforms don't report it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ActivationReason {
+    /// Focus moved to or from another window or app, e.g. because the user alt-tabbed.
+    Focus,
+    /// The window was minimized, or activated again after it had been minimized.
+    Minimized,
+    /// The window lost focus while it became completely hidden by other windows, e.g. on some
+    /// platforms when it is moved to another workspace.
+    Occluded,
+}
+
+/// An event that is sent when the video modes supported by a monitor have changed, e.g. after
+/// plugging in a different cable or switching GPUs.
+///
+/// The [`Monitor::video_modes`](crate::Monitor::video_modes) of the monitor entity have been
+/// updated by the time this is sent, so resolution lists can be rebuilt from them. Known monitors
+/// are checked for changes about once per second.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MonitorVideoModesChanged {
+    /// The [`Monitor`](crate::Monitor) entity whose video modes changed.
+    pub monitor: Entity,
+}
+
+/// An event that is sent when the scale factor of a monitor has changed, e.g. because the user
+/// changed the display scaling in the OS settings.
+///
+/// Unlike [`WindowScaleFactorChanged`], this is sent for every monitor, including monitors that
+/// don't host any of the app's windows. The [`Monitor::scale_factor`](crate::Monitor::scale_factor)
+/// of the monitor entity has been updated by the time this is sent. Known monitors are checked
+/// for changes about once per second.
+#[derive(Event, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MonitorScaleFactorChanged {
+    /// The [`Monitor`](crate::Monitor) entity whose scale factor changed.
+    pub monitor: Entity,
+    /// The new scale factor.
+    pub scale_factor: f64,
+}
+
+/// An event that is sent when the operating system's primary monitor has changed, e.g. because
+/// the user reconfigured their displays.
+///
+/// The primary monitor can change without any monitor being connected or disconnected. This is
+/// also sent when the primary monitor is first detected at startup.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PrimaryMonitorChanged {
+    /// The new [`PrimaryMonitor`](crate::PrimaryMonitor) entity, or `None` if there is no
+    /// primary monitor anymore.
+    pub new_primary: Option<Entity>,
+}
+
+/// An event that requests a screenshot of a window, e.g. for a "screenshot this window" button
+/// in a tool.
+///
+/// The renderer captures the window after its next frame has been presented. If a
+/// [`path`](Self::path) is given the screenshot is saved to it, otherwise it is only reported
+/// through the renderer's `ScreenshotCaptured` observer event.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowScreenshotRequested {
+    /// Window to capture.
+    pub window: Entity,
+    /// Where to save the screenshot to, the image format is chosen from its extension.
+    pub path: Option<PathBuf>,
+}
+
+impl WindowScreenshotRequested {
+    /// Requests a screenshot of `window` that is saved to `path`.
+    pub fn save_to(window: Entity, path: impl Into<PathBuf>) -> Self {
+        Self {
+            window,
+            path: Some(path.into()),
+        }
+    }
+
+    /// Requests a screenshot of `window` that is only reported to observers.
+    pub fn capture(window: Entity) -> Self {
+        Self { window, path: None }
+    }
+}
+
 /// An event sent when the system theme changes for a window.
 ///
 /// This event is only sent when the window is relying on the system theme to control its appearance.
@@ -372,6 +668,26 @@ pub struct WindowThemeChanged {
     pub theme: WindowTheme,
 }
 
+/// An event sent when the cursor grab mode of a window has been applied by the windowing backend.
+///
+/// Since not every platform supports every [`CursorGrabMode`], the backend may fall back to
+/// another mode than the one in [`CursorOptions::grab_mode`](crate::CursorOptions::grab_mode).
+/// [`grab_mode`](Self::grab_mode) is the mode that actually took effect, e.g. to adjust mouse
+/// sensitivity depending on whether the cursor is locked or merely confined.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct CursorGrabChanged {
+    /// Window for which the cursor grab mode has changed.
+    pub window: Entity,
+    /// The grab mode that took effect.
+    pub grab_mode: CursorGrabMode,
+}
+
 /// Application lifetime events
 #[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
@@ -424,8 +740,11 @@ pub enum WindowEvent {
     AppLifecycle(AppLifecycle),
     CursorEntered(CursorEntered),
     CursorLeft(CursorLeft),
+    RawMouseMotion(RawMouseMotion),
     CursorMoved(CursorMoved),
     FileDragAndDrop(FileDragAndDrop),
+    HoveredFile(HoveredFile),
+    HoveredFileCancelled(HoveredFileCancelled),
     Ime(Ime),
     RequestRedraw(RequestRedraw),
     WindowBackendScaleFactorChanged(WindowBackendScaleFactorChanged),
@@ -436,6 +755,10 @@ pub enum WindowEvent {
     WindowMoved(WindowMoved),
     WindowOccluded(WindowOccluded),
     WindowResized(WindowResized),
+    WindowSurfaceNeedsReconfigure(WindowSurfaceNeedsReconfigure),
+    WindowSurfaceLost(WindowSurfaceLost),
+    WindowSurfaceRestored(WindowSurfaceRestored),
+    WindowReady(WindowReady),
     WindowScaleFactorChanged(WindowScaleFactorChanged),
     WindowThemeChanged(WindowThemeChanged),
 
@@ -447,6 +770,7 @@ pub enum WindowEvent {
     RotationGesture(RotationGesture),
     DoubleTapGesture(DoubleTapGesture),
     PanGesture(PanGesture),
+    GestureInput(GestureInput),
 
     TouchInput(TouchInput),
 
@@ -464,6 +788,11 @@ impl From<CursorEntered> for WindowEvent {
         Self::CursorEntered(e)
     }
 }
+impl From<RawMouseMotion> for WindowEvent {
+    fn from(e: RawMouseMotion) -> Self {
+        Self::RawMouseMotion(e)
+    }
+}
 impl From<CursorLeft> for WindowEvent {
     fn from(e: CursorLeft) -> Self {
         Self::CursorLeft(e)
@@ -479,6 +808,16 @@ impl From<FileDragAndDrop> for WindowEvent {
         Self::FileDragAndDrop(e)
     }
 }
+impl From<HoveredFile> for WindowEvent {
+    fn from(e: HoveredFile) -> Self {
+        Self::HoveredFile(e)
+    }
+}
+impl From<HoveredFileCancelled> for WindowEvent {
+    fn from(e: HoveredFileCancelled) -> Self {
+        Self::HoveredFileCancelled(e)
+    }
+}
 impl From<Ime> for WindowEvent {
     fn from(e: Ime) -> Self {
         Self::Ime(e)
@@ -519,6 +858,26 @@ impl From<WindowMoved> for WindowEvent {
         Self::WindowMoved(e)
     }
 }
+impl From<WindowSurfaceLost> for WindowEvent {
+    fn from(e: WindowSurfaceLost) -> Self {
+        Self::WindowSurfaceLost(e)
+    }
+}
+impl From<WindowSurfaceRestored> for WindowEvent {
+    fn from(e: WindowSurfaceRestored) -> Self {
+        Self::WindowSurfaceRestored(e)
+    }
+}
+impl From<WindowSurfaceNeedsReconfigure> for WindowEvent {
+    fn from(e: WindowSurfaceNeedsReconfigure) -> Self {
+        Self::WindowSurfaceNeedsReconfigure(e)
+    }
+}
+impl From<WindowReady> for WindowEvent {
+    fn from(e: WindowReady) -> Self {
+        Self::WindowReady(e)
+    }
+}
 impl From<WindowOccluded> for WindowEvent {
     fn from(e: WindowOccluded) -> Self {
         Self::WindowOccluded(e)
@@ -569,6 +928,11 @@ impl From<DoubleTapGesture> for WindowEvent {
         Self::DoubleTapGesture(e)
     }
 }
+impl From<GestureInput> for WindowEvent {
+    fn from(e: GestureInput) -> Self {
+        Self::GestureInput(e)
+    }
+}
 impl From<PanGesture> for WindowEvent {
     fn from(e: PanGesture) -> Self {
         Self::PanGesture(e)
@@ -23,6 +23,7 @@ use bevy_platform_support::sync::Mutex;
 
 mod event;
 mod monitor;
+mod presentation;
 mod raw_handle;
 mod system;
 mod system_cursor;
@@ -35,6 +36,7 @@ pub use android_activity;
 
 pub use event::*;
 pub use monitor::*;
+pub use presentation::*;
 pub use system::*;
 pub use system_cursor::*;
 pub use window::*;
@@ -101,6 +103,10 @@ impl Plugin for WindowPlugin {
         // User convenience events
         app.add_event::<WindowEvent>()
             .add_event::<WindowResized>()
+            .add_event::<WindowSurfaceNeedsReconfigure>()
+            .add_event::<WindowSurfaceLost>()
+            .add_event::<WindowSurfaceRestored>()
+            .add_event::<WindowReady>()
             .add_event::<WindowCreated>()
             .add_event::<WindowClosing>()
             .add_event::<WindowClosed>()
@@ -110,15 +116,26 @@ impl Plugin for WindowPlugin {
             .add_event::<CursorMoved>()
             .add_event::<CursorEntered>()
             .add_event::<CursorLeft>()
+            .add_event::<RawMouseMotion>()
+            .add_event::<CursorGrabChanged>()
             .add_event::<Ime>()
             .add_event::<WindowFocused>()
             .add_event::<WindowOccluded>()
             .add_event::<WindowScaleFactorChanged>()
             .add_event::<WindowBackendScaleFactorChanged>()
             .add_event::<FileDragAndDrop>()
+            .add_event::<HoveredFile>()
+            .add_event::<HoveredFileCancelled>()
             .add_event::<WindowMoved>()
+            .add_event::<WindowMovedToMonitor>()
+            .add_event::<WindowActivationChanged>()
+            .add_event::<PrimaryMonitorChanged>()
+            .add_event::<MonitorVideoModesChanged>()
+            .add_event::<MonitorScaleFactorChanged>()
+            .add_event::<WindowScreenshotRequested>()
             .add_event::<WindowThemeChanged>()
-            .add_event::<AppLifecycle>();
+            .add_event::<AppLifecycle>()
+            .init_resource::<WindowPresentation>();
 
         if let Some(primary_window) = &self.primary_window {
             app.world_mut().spawn(primary_window.clone()).insert((
@@ -146,6 +163,10 @@ impl Plugin for WindowPlugin {
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<WindowEvent>()
             .register_type::<WindowResized>()
+            .register_type::<WindowSurfaceNeedsReconfigure>()
+            .register_type::<WindowSurfaceLost>()
+            .register_type::<WindowSurfaceRestored>()
+            .register_type::<WindowReady>()
             .register_type::<RequestRedraw>()
             .register_type::<WindowCreated>()
             .register_type::<WindowCloseRequested>()
@@ -154,12 +175,23 @@ impl Plugin for WindowPlugin {
             .register_type::<CursorMoved>()
             .register_type::<CursorEntered>()
             .register_type::<CursorLeft>()
+            .register_type::<RawMouseMotion>()
+            .register_type::<CursorGrabChanged>()
             .register_type::<WindowFocused>()
             .register_type::<WindowOccluded>()
             .register_type::<WindowScaleFactorChanged>()
             .register_type::<WindowBackendScaleFactorChanged>()
             .register_type::<FileDragAndDrop>()
+            .register_type::<HoveredFile>()
+            .register_type::<HoveredFileCancelled>()
             .register_type::<WindowMoved>()
+            .register_type::<WindowMovedToMonitor>()
+            .register_type::<WindowActivationChanged>()
+            .register_type::<ActivationReason>()
+            .register_type::<PrimaryMonitorChanged>()
+            .register_type::<MonitorVideoModesChanged>()
+            .register_type::<MonitorScaleFactorChanged>()
+            .register_type::<WindowScreenshotRequested>()
             .register_type::<WindowThemeChanged>()
             .register_type::<AppLifecycle>()
             .register_type::<Monitor>();
@@ -167,7 +199,9 @@ impl Plugin for WindowPlugin {
         // Register window descriptor and related types
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<Window>()
-            .register_type::<PrimaryWindow>();
+            .register_type::<PrimaryWindow>()
+            .register_type::<TransparentWindow>()
+            .register_type::<PreventClose>();
     }
 }
 
@@ -0,0 +1,80 @@
+use alloc::{boxed::Box, sync::Arc};
+
+use bevy_ecs::{
+    entity::{hash_map::EntityHashMap, hash_set::EntityHashSet, Entity},
+    resource::Resource,
+};
+use bevy_platform_support::sync::Mutex;
+
+/// Reports which windows the renderer has presented frames to, shared between the main world
+/// and the render world.
+///
+/// Renderers call [`pre_present`](Self::pre_present) right before and
+/// [`presented`](Self::presented) right after presenting a frame to a window. The windowing
+/// backend can register a hook to run before presenting, and collects the windows with
+/// [`take_presented`](Self::take_presented), e.g. to keep windows hidden until they have content.
+/// With pipelined rendering, a frame is presented while the next update already runs, so this can
+/// lag behind the update that produced it.
+#[derive(Resource, Clone, Default)]
+pub struct WindowPresentation(Arc<Mutex<PresentationState>>);
+
+#[derive(Default)]
+struct PresentationState {
+    renderer_attached: bool,
+    presented: EntityHashSet,
+    pre_present_hooks: EntityHashMap<Box<dyn Fn() + Send + Sync>>,
+}
+
+impl WindowPresentation {
+    /// Marks that a renderer reports its presented frames.
+    ///
+    /// Without a renderer, nothing is ever presented, so windowing backends treat each
+    /// completed update as presenting its windows instead.
+    pub fn attach_renderer(&self) {
+        self.0.lock().unwrap().renderer_attached = true;
+    }
+
+    /// Returns whether a renderer reports its presented frames, see
+    /// [`attach_renderer`](Self::attach_renderer).
+    pub fn has_renderer(&self) -> bool {
+        self.0.lock().unwrap().renderer_attached
+    }
+
+    /// Sets the hook that runs right before a frame is presented to the window, e.g. to let
+    /// the compositor pace frames.
+    ///
+    /// Hooks run on the render thread while the resource is locked, so they should be cheap. They
+    /// are dropped on the thread that replaces or removes them.
+    pub fn set_pre_present_hook(&self, window: Entity, hook: impl Fn() + Send + Sync + 'static) {
+        self.0
+            .lock()
+            .unwrap()
+            .pre_present_hooks
+            .insert(window, Box::new(hook));
+    }
+
+    /// Removes the hook and the presented frames of a window that is being closed.
+    pub fn remove_window(&self, window: Entity) {
+        let mut state = self.0.lock().unwrap();
+        state.pre_present_hooks.remove(&window);
+        state.presented.remove(&window);
+    }
+
+    /// Runs the hook of the window before a frame is presented to it, see
+    /// [`set_pre_present_hook`](Self::set_pre_present_hook).
+    pub fn pre_present(&self, window: Entity) {
+        if let Some(hook) = self.0.lock().unwrap().pre_present_hooks.get(&window) {
+            hook();
+        }
+    }
+
+    /// Records that a frame has been presented to the window.
+    pub fn presented(&self, window: Entity) {
+        self.0.lock().unwrap().presented.insert(window);
+    }
+
+    /// Returns the windows that frames have been presented to since the last call.
+    pub fn take_presented(&self) -> EntityHashSet {
+        core::mem::take(&mut self.0.lock().unwrap().presented)
+    }
+}
@@ -35,6 +35,15 @@ impl<W: Send + Sync + 'static> WindowWrapper<W> {
     }
 }
 
+impl<W> Clone for WindowWrapper<W> {
+    fn clone(&self) -> Self {
+        WindowWrapper {
+            reference: self.reference.clone(),
+            ty: PhantomData,
+        }
+    }
+}
+
 impl<W: 'static> Deref for WindowWrapper<W> {
     type Target = W;
 
@@ -69,6 +78,30 @@ impl RawHandleWrapper {
         })
     }
 
+    /// Creates a `RawHandleWrapper` from a window that is owned outside of Bevy, e.g. the window
+    /// of a host application Bevy is embedded in.
+    ///
+    /// Spawning this next to a [`Window`](crate::Window) makes the renderer draw to the external
+    /// window; window backends don't create a window of their own for such entities, and won't
+    /// apply [`Window`](crate::Window) changes or report events for it.
+    ///
+    /// # Safety
+    ///
+    /// The handles must be valid and must stay valid for as long as this wrapper or any of its
+    /// clones exist, which includes clones held by a pipelined renderer for frames in flight.
+    /// Destroying the external window before the [`Window`](crate::Window) entity is despawned
+    /// and the renderer has released its clones is undefined behavior.
+    pub unsafe fn from_raw(
+        window_handle: RawWindowHandle,
+        display_handle: RawDisplayHandle,
+    ) -> RawHandleWrapper {
+        RawHandleWrapper {
+            _window: Arc::new(()),
+            window_handle,
+            display_handle,
+        }
+    }
+
     /// Returns a [`HasWindowHandle`] + [`HasDisplayHandle`] impl, which exposes [`WindowHandle`] and [`DisplayHandle`].
     ///
     /// # Safety
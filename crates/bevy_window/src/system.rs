@@ -1,4 +1,4 @@
-use crate::{ClosingWindow, PrimaryWindow, Window, WindowCloseRequested};
+use crate::{ClosingWindow, PreventClose, PrimaryWindow, Window, WindowCloseRequested};
 
 use bevy_app::AppExit;
 use bevy_ecs::prelude::*;
@@ -34,6 +34,8 @@ pub fn exit_on_primary_closed(
 
 /// Close windows in response to [`WindowCloseRequested`] (e.g.  when the close button is pressed).
 ///
+/// Windows with the [`PreventClose`] component are left open.
+///
 /// This system is added by the [`WindowPlugin`] in the default configuration.
 /// To disable this behavior, set `close_when_requested` (on the [`WindowPlugin`]) to `false`.
 /// Ensure that you read the caveats documented on that field if doing so.
@@ -43,6 +45,7 @@ pub fn close_when_requested(
     mut commands: Commands,
     mut closed: EventReader<WindowCloseRequested>,
     closing: Query<Entity, With<ClosingWindow>>,
+    prevent_close: Query<(), With<PreventClose>>,
 ) {
     // This was inserted by us on the last frame so now we can despawn the window
     for window in closing.iter() {
@@ -50,9 +53,36 @@ pub fn close_when_requested(
     }
     // Mark the window as closing so we can despawn it on the next frame
     for event in closed.read() {
+        if prevent_close.contains(event.window) {
+            continue;
+        }
         // When spamming the window close button on windows (other platforms too probably)
         // we may receive a `WindowCloseRequested` for a window we've just despawned in the above
         // loop.
         commands.entity(event.window).try_insert(ClosingWindow);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prevent_close_vetoes_close_requests() {
+        let mut world = World::new();
+        world.init_resource::<Events<WindowCloseRequested>>();
+        let closed = world.spawn(Window::default()).id();
+        let vetoed = world.spawn((Window::default(), PreventClose)).id();
+        world.send_event(WindowCloseRequested { window: closed });
+        world.send_event(WindowCloseRequested { window: vetoed });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(close_when_requested);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        assert!(world.get_entity(closed).is_err());
+        assert!(world.get_entity(vetoed).is_ok());
+        assert!(world.get::<ClosingWindow>(vetoed).is_none());
+    }
+}
@@ -1,11 +1,12 @@
-use alloc::{borrow::ToOwned, string::String};
-use core::num::NonZero;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{num::NonZero, time::Duration};
 
+use bevy_color::Color;
 use bevy_ecs::{
     entity::{Entity, EntityBorrow, VisitEntities, VisitEntitiesMut},
     prelude::Component,
 };
-use bevy_math::{CompassOctant, DVec2, IVec2, UVec2, Vec2};
+use bevy_math::{ops, CompassOctant, DVec2, IVec2, Rect, URect, UVec2, Vec2};
 use log::warn;
 
 #[cfg(feature = "bevy_reflect")]
@@ -32,6 +33,78 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 )]
 pub struct PrimaryWindow;
 
+/// Marker [`Component`] for windows that were created with a transparent background, see
+/// [`Window::transparent`] and [`Window::shape_mask`].
+///
+/// The windowing backend inserts this when it creates the window, since transparency can't be
+/// enabled afterwards. The transparency only shows if the window is cleared to a color with zero
+/// alpha, so renderers can use this to warn about transparent windows cleared to an opaque color.
+#[derive(Default, Debug, Component, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, Default, PartialEq)
+)]
+pub struct TransparentWindow;
+
+/// A non-rectangular area of a window made of polygons, see [`Window::shape_mask`].
+///
+/// Points are in logical window coordinates, with the origin at the top-left corner of the
+/// window. Each polygon is closed implicitly, and a point is inside the region if it is inside
+/// any of its polygons.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Region {
+    /// The polygons making up the region.
+    pub polygons: Vec<Vec<Vec2>>,
+}
+
+impl Region {
+    /// The number of edges used to approximate a circle.
+    const CIRCLE_SEGMENTS: usize = 64;
+
+    /// Creates a region from a single polygon.
+    pub fn polygon(points: impl IntoIterator<Item = Vec2>) -> Self {
+        Self {
+            polygons: alloc::vec![points.into_iter().collect()],
+        }
+    }
+
+    /// Creates a rectangular region.
+    pub fn rect(rect: Rect) -> Self {
+        Self::polygon([
+            rect.min,
+            Vec2::new(rect.max.x, rect.min.y),
+            rect.max,
+            Vec2::new(rect.min.x, rect.max.y),
+        ])
+    }
+
+    /// Creates a circular region, approximated by a polygon.
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::polygon((0..Self::CIRCLE_SEGMENTS).map(|i| {
+            let angle = i as f32 / Self::CIRCLE_SEGMENTS as f32 * core::f32::consts::TAU;
+            let (sin, cos) = ops::sin_cos(angle);
+            center + Vec2::new(cos, sin) * radius
+        }))
+    }
+
+    /// Adds the polygons of `other` to this region.
+    pub fn union(mut self, other: Region) -> Self {
+        self.polygons.extend(other.polygons);
+        self
+    }
+}
+
 /// Reference to a [`Window`], whether it be a direct link to a specific entity or
 /// a more vague defaulting choice.
 #[repr(C)]
@@ -150,12 +223,90 @@ pub struct Window {
     /// The cursor options of this window. Cursor icons are set with the `Cursor` component on the
     /// window entity.
     pub cursor_options: CursorOptions,
+    /// Whether raw mouse motion is sent as [`RawMouseMotion`](crate::RawMouseMotion) events
+    /// for this window while it is focused, independently of the cursor's
+    /// [`grab_mode`](CursorOptions::grab_mode).
+    ///
+    /// Raw motion bypasses OS pointer acceleration, e.g. to steer a background camera from a
+    /// menu that still shows the cursor.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android:** Raw mouse motion isn't reported, so no events are sent.
+    pub raw_mouse_motion: bool,
+    /// Where to move the cursor to once the window has been created, in logical pixels relative
+    /// to the top-left corner of the window, e.g. to center it for a game that starts in mouse
+    /// look.
+    ///
+    /// This is only attempted once. To move the cursor later on, use
+    /// [`Window::set_cursor_position`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Wayland:** Unsupported, a warning is logged instead.
+    pub initial_cursor_position: Option<Vec2>,
     /// What presentation mode to give the window.
     pub present_mode: PresentMode,
+    /// The minimum time between two redraw requests for this window, e.g. to run a background
+    /// tool window at 10 frames per second while the main window runs at 60.
+    ///
+    /// Redraw requests made more often are deferred until the interval has elapsed. This only
+    /// throttles how often the window wakes up the app in reactive update modes: rendering isn't
+    /// done per window, and every window is rendered on every app update. While another window
+    /// or event keeps the app updating, e.g. with `UpdateMode::Continuous` in `bevy_winit`, this
+    /// window is rendered just as often as the others. To skip rendering, deactivate the cameras
+    /// targeting the window between its redraws instead.
+    ///
+    /// Defaults to `None`, which redraws the window whenever the app requests it.
+    pub redraw_interval: Option<Duration>,
+    /// How long the window has to stop being resized before it is redrawn, e.g. to skip
+    /// rendering the intermediate sizes of a drag-resize that are immediately superseded.
+    ///
+    /// While resize events keep arriving within this delay, redraws of the window are deferred
+    /// and its resize events don't wake the app, so only the final size of a burst is rendered.
+    /// Other windows and events can still drive an update in between.
+    ///
+    /// Defaults to `None`, which redraws the window on every resize.
+    pub resize_redraw_delay: Option<Duration>,
+    /// The largest rendering surface of the window in physical pixels, e.g. to avoid running out
+    /// of video memory when a low-end device goes fullscreen on a huge monitor.
+    ///
+    /// Larger windows render to a surface that is scaled down to fit, keeping its aspect ratio,
+    /// and is then upscaled to the full size of the window by the renderer. The swapchain of
+    /// the window keeps its full size. See [`Window::surface_size`].
+    ///
+    /// Defaults to `None`, which renders at the full size of the window.
+    pub max_surface_size: Option<UVec2>,
     /// Which fullscreen or windowing mode should be used.
+    ///
+    /// This is updated to the mode the window actually has if it falls back to another mode,
+    /// e.g. because its monitor is unavailable, or if the OS changes it, e.g. when the user
+    /// leaves fullscreen with a keyboard shortcut.
     pub mode: WindowMode,
+    /// The aspect ratio (width divided by height) the content of the window keeps in fullscreen,
+    /// e.g. to letterbox a 16:9 game on an ultrawide monitor.
+    ///
+    /// The window still covers the whole monitor, and cameras rendering to it are given the
+    /// centered viewport returned by [`Window::letterbox`], leaving bars in the clear color.
+    /// Cameras with their own viewport are left alone.
+    ///
+    /// Defaults to `None`, which renders to the whole window.
+    pub fullscreen_aspect_lock: Option<f32>,
     /// Where the window should be placed.
     pub position: WindowPosition,
+    /// Whether a [`WindowPosition::At`] position is moved so the window lies entirely on a
+    /// monitor, e.g. so a saved position from a disconnected monitor doesn't open the window
+    /// off-screen.
+    ///
+    /// This applies when the window is created and whenever [`Window::position`] changes. The
+    /// window is moved onto the monitor it overlaps the most, or the nearest monitor if it is
+    /// entirely off-screen. Moving the window by dragging it doesn't count as a change, so the
+    /// user can still drag it partially off-screen.
+    ///
+    /// Defaults to `true`.
+    pub keep_on_screen: bool,
     /// What resolution the window should have.
     pub resolution: WindowResolution,
     /// Stores the title of the window.
@@ -173,13 +324,26 @@ pub struct Window {
     /// - **`X11`**: Can only be set while building the window, setting the window's `WM_CLASS`.
     /// - **`macOS`**, **`iOS`**, **`Android`**, and **`Web`**: not applicable.
     ///
+    /// The [`general`](WindowName::general) and [`instance`](WindowName::instance) parts can be
+    /// set separately, see [`WindowName`]. Converting from a single string only sets the general name.
+    ///
     /// Notes: Changing this field during runtime will have no effect for now.
-    pub name: Option<String>,
+    pub name: Option<WindowName>,
     /// How the alpha channel of textures should be handled while compositing.
     pub composite_alpha_mode: CompositeAlphaMode,
     /// The limits of the window's logical size
     /// (found in its [`resolution`](WindowResolution)) when resizing.
     pub resize_constraints: WindowResizeConstraints,
+    /// Keeps the window at the given aspect ratio (width divided by height) while the user
+    /// resizes it.
+    ///
+    /// `winit` can't enforce this, so resizes that drift away from the ratio are corrected with a
+    /// new size request right afterwards, keeping the dimension the user changed the most. This
+    /// is skipped while the window is fullscreen or maximized. Use letterboxing to cover any
+    /// brief mismatch.
+    ///
+    /// Defaults to `None`, which allows any aspect ratio.
+    pub preserve_aspect_ratio: Option<f32>,
     /// Should the window be resizable?
     ///
     /// Note: This does not stop the program from fullscreening/setting
@@ -212,8 +376,48 @@ pub struct Window {
     /// macOS transparent works with winit out of the box, so this issue might be related to: <https://github.com/gfx-rs/wgpu/issues/687>.
     /// You should also set the window `composite_alpha_mode` to `CompositeAlphaMode::PostMultiplied`.
     pub transparent: bool,
+    /// The shape of the window, e.g. a circle for a clock widget.
+    ///
+    /// Most platforms don't support non-rectangular windows, so this is emulated: the window is
+    /// created [`transparent`](Self::transparent), and clicks outside the shape pass through to
+    /// the windows beneath like with `WinitWindows::set_hittest_mask` in `bevy_winit`.
+    /// The app is responsible for rendering only inside the shape and clearing the rest to a
+    /// transparent color.
+    ///
+    /// Transparency can only be enabled when the window is created, so adding a shape to an
+    /// existing window only affects input unless the window was created transparent.
+    ///
+    /// Outside the shape, the window no longer receives cursor events, so the cursor is followed
+    /// through raw mouse motion, which is received even while another app is focused, and
+    /// [`cursor_position`](Self::cursor_position) keeps the last position inside the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Wayland:** Unsupported, clicks outside the shape are captured.
+    pub shape_mask: Option<Region>,
     /// Get/set whether the window is focused.
+    ///
+    /// If `false` when the window is created, it is created without taking focus from the
+    /// currently focused window, e.g. for utility or overlay windows that should appear in the
+    /// background without interrupting the user.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Android / iOS / X11 / Wayland:** Windows always take focus on creation.
     pub focused: bool,
+    /// Whether the window is currently occluded, i.e. completely hidden from view by other
+    /// windows while still being open.
+    ///
+    /// This is `false` when the window is created and is updated from [`WindowOccluded`](crate::WindowOccluded)
+    /// events, e.g. to throttle rendering while nothing of the window can be seen. It is
+    /// distinct from [`Window::focused`] and from the window being minimized. Changing it has
+    /// no effect on the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS:** The window counts as occluded while the app is in the background.
+    /// - **Android / Wayland / Windows:** Unsupported, this always stays `false`.
+    pub occluded: bool,
     /// Where should the window appear relative to other overlapping window.
     ///
     /// ## Platform-specific
@@ -280,16 +484,45 @@ pub struct Window {
     ///
     /// - **Android / Wayland / Web:** Unsupported.
     pub visible: bool,
+    /// Whether to keep the window hidden until the first frame has been rendered.
+    ///
+    /// If `true` and [`visible`](Self::visible) is `true`, the window is created hidden and
+    /// only shown once the app has completed its first update after the window was created,
+    /// avoiding a flash of unpainted content on startup. Has no effect after the window is
+    /// created.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Android / Wayland / Web:** Unsupported.
+    pub visible_on_first_frame: bool,
+    /// Whether the window should be created maximized.
+    ///
+    /// Maximizing takes precedence over [`position`](Self::position) and
+    /// [`resolution`](Self::resolution), which are still used as the size and position the
+    /// window is restored to when un-maximized. The resolution is updated to the maximized size
+    /// once the window has been created. Has no effect after the window is created, use
+    /// [`Window::set_maximized`] instead.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web:** Unsupported.
+    pub start_maximized: bool,
     /// Sets whether the window should be shown in the taskbar.
     ///
     /// If `true`, the window will not appear in the taskbar.
     /// If `false`, the window will appear in the taskbar.
     ///
-    /// Note that this will only take effect on window creation.
+    /// Overlay and splash windows usually want this to keep them out of the OS task switcher.
+    /// Can be changed at runtime.
     ///
     /// ## Platform-specific
     ///
-    /// - Only supported on Windows.
+    /// - **Windows:** Supported, the window is also excluded from the alt-tab switcher.
+    /// - **macOS:** Unsupported per window, `winit` only exposes this for the whole app through
+    ///   its activation policy.
+    /// - **X11 / Wayland / Web / iOS / Android:** Unsupported.
+    ///
+    /// A warning is logged once when this is enabled on an unsupported platform.
     pub skip_taskbar: bool,
     /// Sets whether the window should draw over its child windows.
     ///
@@ -300,6 +533,79 @@ pub struct Window {
     ///
     /// - Only supported on Windows.
     pub clip_children: bool,
+    /// Sets whether an undecorated window keeps the shadow drawn by the OS.
+    ///
+    /// Only has an effect when [`Window::decorations`] is `false`. Custom-chrome apps can use
+    /// this together with [`Window::corner_preference`] to keep the native look without the
+    /// default title bar.
+    ///
+    /// Corresponds to [`WindowAttributesExtWindows::with_undecorated_shadow`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on Windows.
+    ///
+    /// [`WindowAttributesExtWindows::with_undecorated_shadow`]: https://docs.rs/winit/latest/x86_64-pc-windows-msvc/winit/platform/windows/trait.WindowAttributesExtWindows.html#tymethod.with_undecorated_shadow
+    pub undecorated_shadow: bool,
+    /// Sets how the corners of the window should be rounded.
+    ///
+    /// Corresponds to [`WindowAttributesExtWindows::with_corner_preference`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on Windows 11.
+    ///
+    /// [`WindowAttributesExtWindows::with_corner_preference`]: https://docs.rs/winit/latest/x86_64-pc-windows-msvc/winit/platform/windows/trait.WindowAttributesExtWindows.html#tymethod.with_corner_preference
+    pub corner_preference: WindowCornerPreference,
+    /// Sets the color of the window border.
+    ///
+    /// Can be changed at runtime. Corresponds to [`WindowAttributesExtWindows::with_border_color`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on Windows 11.
+    ///
+    /// [`WindowAttributesExtWindows::with_border_color`]: https://docs.rs/winit/latest/x86_64-pc-windows-msvc/winit/platform/windows/trait.WindowAttributesExtWindows.html#tymethod.with_border_color
+    pub border_color: WindowChromeColor,
+    /// Sets the background color of the title bar.
+    ///
+    /// Can be changed at runtime. Corresponds to [`WindowAttributesExtWindows::with_title_background_color`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on Windows 11.
+    ///
+    /// [`WindowAttributesExtWindows::with_title_background_color`]: https://docs.rs/winit/latest/x86_64-pc-windows-msvc/winit/platform/windows/trait.WindowAttributesExtWindows.html#tymethod.with_title_background_color
+    pub title_background_color: WindowChromeColor,
+    /// Sets the color of the title text.
+    ///
+    /// Can be changed at runtime. Corresponds to [`WindowAttributesExtWindows::with_title_text_color`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on Windows 11.
+    ///
+    /// [`WindowAttributesExtWindows::with_title_text_color`]: https://docs.rs/winit/latest/x86_64-pc-windows-msvc/winit/platform/windows/trait.WindowAttributesExtWindows.html#tymethod.with_title_text_color
+    pub title_text_color: WindowChromeColor,
+    /// Sets the material drawn behind the window by the system, e.g. a translucent blur.
+    ///
+    /// The backdrop is only visible through transparent parts of the window, so
+    /// [`Window::transparent`] needs to be set, the window needs to be cleared with a
+    /// transparent color and [`Window::composite_alpha_mode`] may need to be changed.
+    /// Decorations can be kept, but decorated windows draw an opaque title bar on some platforms.
+    ///
+    /// Corresponds to [`WindowAttributesExtWindows::with_system_backdrop`] on Windows.
+    ///
+    /// # Platform-specific
+    ///
+    /// - **Windows:** Only used on Windows 11 build 22523 and later.
+    /// - **macOS / Wayland:** Every backdrop other than [`WindowBackdrop::Auto`] and
+    ///   [`WindowBackdrop::None`] blurs the content behind the window. On Wayland this requires a
+    ///   compositor supporting `org_kde_kwin_blur_manager`.
+    /// - **iOS / Android / Web / X11 / Orbital:** Unsupported.
+    ///
+    /// [`WindowAttributesExtWindows::with_system_backdrop`]: https://docs.rs/winit/latest/x86_64-pc-windows-msvc/winit/platform/windows/trait.WindowAttributesExtWindows.html#tymethod.with_system_backdrop
+    pub system_backdrop: WindowBackdrop,
     /// Optional hint given to the rendering API regarding the maximum number of queued frames admissible on the GPU.
     ///
     /// Given values are usually within the 1-3 range. If not provided, this will default to 2.
@@ -309,6 +615,12 @@ pub struct Window {
     /// [`wgpu::SurfaceConfiguration::desired_maximum_frame_latency`]:
     /// https://docs.rs/wgpu/latest/wgpu/type.SurfaceConfiguration.html#structfield.desired_maximum_frame_latency
     pub desired_maximum_frame_latency: Option<NonZero<u32>>,
+    /// Whether this window prefers low input latency or smooth frame pacing.
+    ///
+    /// The renderer uses this to choose the surface's present mode and frame latency. Any value
+    /// other than [`PresentLatency::Default`] takes precedence over [`Window::present_mode`], while
+    /// an explicit [`Window::desired_maximum_frame_latency`] still wins over the latency hint.
+    pub desired_present_latency: PresentLatency,
     /// Sets whether this window recognizes [`PinchGesture`](https://docs.rs/bevy/latest/bevy/input/gestures/struct.PinchGesture.html)
     ///
     /// ## Platform-specific
@@ -411,6 +723,32 @@ pub struct Window {
     ///
     /// [`WindowAttributesExtMacOS::with_titlebar_buttons_hidden`]: https://docs.rs/winit/latest/x86_64-apple-darwin/winit/platform/macos/trait.WindowAttributesExtMacOS.html#tymethod.with_titlebar_buttons_hidden
     pub titlebar_show_buttons: bool,
+    /// Uses "simple" fullscreen for [`WindowMode::BorderlessFullscreen`].
+    ///
+    /// Native macOS fullscreen moves the window to a separate Space with a slow animation.
+    /// Simple fullscreen instead resizes the window to cover the screen it is on, and restores
+    /// its prior placement when leaving fullscreen.
+    ///
+    /// Corresponds to [`WindowExtMacOS::set_simple_fullscreen`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on macOS.
+    ///
+    /// [`WindowExtMacOS::set_simple_fullscreen`]: https://docs.rs/winit/latest/x86_64-apple-darwin/winit/platform/macos/trait.WindowExtMacOS.html#tymethod.set_simple_fullscreen
+    pub simple_fullscreen: bool,
+    /// Groups the window into native tabs with other windows that share this identifier.
+    ///
+    /// Can be changed at runtime, see [`WinitWindows::select_next_tab`] and related methods to
+    /// switch between tabs. Corresponds to [`WindowAttributesExtMacOS::with_tabbing_identifier`].
+    ///
+    /// # Platform-specific
+    ///
+    /// - Only used on macOS.
+    ///
+    /// [`WinitWindows::select_next_tab`]: https://docs.rs/bevy/latest/bevy/winit/struct.WinitWindows.html#method.select_next_tab
+    /// [`WindowAttributesExtMacOS::with_tabbing_identifier`]: https://docs.rs/winit/latest/x86_64-apple-darwin/winit/platform/macos/trait.WindowAttributesExtMacOS.html#tymethod.with_tabbing_identifier
+    pub tabbing_identifier: Option<String>,
     /// Sets whether the Window prefers the home indicator hidden.
     ///
     /// Corresponds to [`WindowAttributesExtIOS::with_prefers_home_indicator_hidden`].
@@ -439,29 +777,48 @@ impl Default for Window {
             title: "App".to_owned(),
             name: None,
             cursor_options: Default::default(),
+            raw_mouse_motion: false,
+            initial_cursor_position: None,
             present_mode: Default::default(),
+            redraw_interval: None,
+            resize_redraw_delay: None,
+            max_surface_size: None,
+            fullscreen_aspect_lock: None,
             mode: Default::default(),
             position: Default::default(),
+            keep_on_screen: true,
             resolution: Default::default(),
             internal: Default::default(),
             composite_alpha_mode: Default::default(),
             resize_constraints: Default::default(),
+            preserve_aspect_ratio: None,
             ime_enabled: Default::default(),
             ime_position: Default::default(),
             resizable: true,
             enabled_buttons: Default::default(),
             decorations: true,
             transparent: false,
+            shape_mask: None,
             focused: true,
+            occluded: false,
             window_level: Default::default(),
             fit_canvas_to_parent: false,
             prevent_default_event_handling: true,
             canvas: None,
             window_theme: None,
             visible: true,
+            visible_on_first_frame: false,
+            start_maximized: false,
             skip_taskbar: false,
             clip_children: true,
+            undecorated_shadow: false,
+            corner_preference: WindowCornerPreference::Default,
+            border_color: WindowChromeColor::System,
+            title_background_color: WindowChromeColor::System,
+            title_text_color: WindowChromeColor::System,
+            system_backdrop: WindowBackdrop::Auto,
             desired_maximum_frame_latency: None,
+            desired_present_latency: PresentLatency::Default,
             recognize_pinch_gesture: false,
             recognize_rotation_gesture: false,
             recognize_doubletap_gesture: false,
@@ -473,6 +830,8 @@ impl Default for Window {
             titlebar_transparent: false,
             titlebar_show_title: true,
             titlebar_show_buttons: true,
+            simple_fullscreen: false,
+            tabbing_identifier: None,
             prefers_home_indicator_hidden: false,
             prefers_status_bar_hidden: false,
         }
@@ -480,6 +839,25 @@ impl Default for Window {
 }
 
 impl Window {
+    /// Returns a hidden [`Window`] that stays out of the task bar and task switcher and doesn't
+    /// take focus when created, e.g. for background integrations that only need a native window
+    /// handle for the clipboard, global hotkeys or a tray icon.
+    ///
+    /// The window stays hidden as long as [`Window::visible`] isn't set. See
+    /// [`Window::skip_taskbar`] and [`Window::focused`] for platform support.
+    pub fn utility() -> Self {
+        Self {
+            visible: false,
+            focused: false,
+            // Hidden windows aren't listed elsewhere, and this only has an effect on Windows.
+            skip_taskbar: cfg!(target_os = "windows"),
+            decorations: false,
+            resizable: false,
+            window_level: WindowLevel::Normal,
+            ..Default::default()
+        }
+    }
+
     /// Setting to true will attempt to maximize the window.
     ///
     /// Setting to false will attempt to un-maximize the window.
@@ -558,6 +936,47 @@ impl Window {
         self.resolution.physical_size()
     }
 
+    /// The size of the window's rendering surface in physical pixels, which cameras rendering
+    /// to the window render at.
+    ///
+    /// This is the [`physical_size`](Self::physical_size) of the window, scaled down to fit
+    /// within [`Window::max_surface_size`] while keeping its aspect ratio.
+    pub fn surface_size(&self) -> UVec2 {
+        let size = self.physical_size();
+        let Some(max) = self.max_surface_size else {
+            return size;
+        };
+        if size.x <= max.x && size.y <= max.y {
+            return size;
+        }
+        let scale = f32::min(max.x as f32 / size.x as f32, max.y as f32 / size.y as f32);
+        (size.as_vec2() * scale).as_uvec2().max(UVec2::ONE)
+    }
+
+    /// The centered rectangle of the window's surface, in physical pixels, that keeps the
+    /// [`Window::fullscreen_aspect_lock`] aspect ratio.
+    ///
+    /// Returns `None` if the window isn't fullscreen, has no aspect lock or has an empty surface.
+    pub fn letterbox(&self) -> Option<URect> {
+        let aspect_ratio = self.fullscreen_aspect_lock?;
+        let surface_size = self.surface_size();
+        if self.mode == WindowMode::Windowed
+            || aspect_ratio <= 0.
+            || surface_size.cmpeq(UVec2::ZERO).any()
+        {
+            return None;
+        }
+        let size = surface_size.as_vec2();
+        let fitted = if size.x / size.y > aspect_ratio {
+            Vec2::new(size.y * aspect_ratio, size.y)
+        } else {
+            Vec2::new(size.x, size.x / aspect_ratio)
+        };
+        let fitted = (fitted + 0.5).as_uvec2().clamp(UVec2::ONE, surface_size);
+        let min = (surface_size - fitted) / 2;
+        Some(URect::from_corners(min, min + fitted))
+    }
+
     /// The window's scale factor.
     ///
     /// Ratio of physical size to logical size, see [`WindowResolution`].
@@ -616,6 +1035,58 @@ impl Window {
     }
 }
 
+/// The application ID / class name of a [`Window`], see [`Window::name`].
+///
+/// ## Platform-specific
+///
+/// - **`X11`**: The window's `WM_CLASS` is set to `"instance", "general"`.
+/// - **`Wayland`**: `general` is used as the application ID, `instance` is ignored.
+/// - **`Windows`**: `general` is used as the window class name, `instance` is ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowName {
+    /// The general name, used as the application ID or class of the window.
+    pub general: String,
+    /// The instance name, which may differ from the general name.
+    ///
+    /// Leave empty to let the platform pick its default.
+    pub instance: String,
+}
+
+impl WindowName {
+    /// Creates a new [`WindowName`] with separate general and instance names.
+    pub fn new(general: impl Into<String>, instance: impl Into<String>) -> Self {
+        Self {
+            general: general.into(),
+            instance: instance.into(),
+        }
+    }
+}
+
+impl From<String> for WindowName {
+    fn from(general: String) -> Self {
+        Self {
+            general,
+            instance: String::new(),
+        }
+    }
+}
+
+impl From<&str> for WindowName {
+    fn from(general: &str) -> Self {
+        general.to_owned().into()
+    }
+}
+
 /// The size limits on a [`Window`].
 ///
 /// These values are measured in logical pixels (see [`WindowResolution`]), so the user's
@@ -724,12 +1195,38 @@ pub struct CursorOptions {
     /// Since `Windows` and `macOS` have different [`CursorGrabMode`] support, we first try to set the grab mode that was asked for. If it doesn't work then use the alternate grab mode.
     pub grab_mode: CursorGrabMode,
 
+    /// Restricts a [`CursorGrabMode::Confined`] grab to this region of the window, in logical
+    /// pixels, e.g. for a minimap or an in-game cursor area.
+    ///
+    /// The cursor is confined to the whole window and moved back to the edge of the region
+    /// whenever it leaves it.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **`Wayland`** and **`Web`** can't move the cursor, so the cursor is only confined to
+    ///   the whole window there, while [`Window::cursor_position`] still stays inside the region.
+    pub confine_region: Option<Rect>,
+
     /// Set whether or not mouse events within *this* window are captured or fall through to the Window below.
     ///
     /// ## Platform-specific
     ///
     /// - iOS / Android / Web / X11: Unsupported.
     pub hit_test: bool,
+
+    /// Hides the cursor while [`grab_mode`](Self::grab_mode) is not [`CursorGrabMode::None`].
+    ///
+    /// Releasing the grab shows the cursor again according to [`visible`](Self::visible), which
+    /// is left untouched, so it doesn't have to be restored manually.
+    pub hide_while_grabbed: bool,
+}
+
+impl CursorOptions {
+    /// Returns whether the cursor should currently be shown, taking
+    /// [`hide_while_grabbed`](Self::hide_while_grabbed) into account.
+    pub fn is_cursor_shown(&self) -> bool {
+        self.visible && !(self.hide_while_grabbed && self.grab_mode != CursorGrabMode::None)
+    }
 }
 
 impl Default for CursorOptions {
@@ -737,13 +1234,15 @@ impl Default for CursorOptions {
         CursorOptions {
             visible: true,
             grab_mode: CursorGrabMode::None,
+            confine_region: None,
             hit_test: true,
+            hide_while_grabbed: false,
         }
     }
 }
 
 /// Defines where a [`Window`] should be placed on the screen.
-#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -753,6 +1252,7 @@ impl Default for CursorOptions {
 pub enum WindowPosition {
     /// Position will be set by the window manager.
     /// Bevy will delegate this decision to the window manager and no guarantees can be made about where the window will be placed.
+    /// Use [`Centered(MonitorSelection::Current)`](WindowPosition::Centered) for predictable placement instead.
     ///
     /// Used at creation but will be changed to [`At`](WindowPosition::At).
     #[default]
@@ -767,6 +1267,70 @@ pub enum WindowPosition {
     ///
     /// (0,0) represents top-left corner of screen space.
     At(IVec2),
+    /// The window should be docked to an edge or corner of the selected monitor, e.g. for an
+    /// overlay in the top-right corner.
+    ///
+    /// The position is computed from the monitor's current size whenever it is applied, and it
+    /// is applied again when the monitors change, e.g. after a resolution change. Unlike the
+    /// other variants, this isn't changed to [`At`](WindowPosition::At) when the window moves.
+    ///
+    /// Note that this does not account for window decorations.
+    AnchoredToMonitor {
+        /// The monitor to dock the window to.
+        monitor: MonitorSelection,
+        /// The edge or corner of the monitor the window is docked to.
+        anchor: MonitorAnchor,
+        /// The distance in physical pixels between the window and the edges it is docked to.
+        /// Ignored along axes the window is centered on.
+        margin: IVec2,
+    },
+}
+
+/// Where on a monitor a [`WindowPosition::AnchoredToMonitor`] window is docked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum MonitorAnchor {
+    /// The top-left corner.
+    TopLeft,
+    /// The middle of the top edge.
+    Top,
+    /// The top-right corner.
+    TopRight,
+    /// The middle of the left edge.
+    Left,
+    /// The center of the monitor.
+    Center,
+    /// The middle of the right edge.
+    Right,
+    /// The bottom-left corner.
+    BottomLeft,
+    /// The middle of the bottom edge.
+    Bottom,
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+impl MonitorAnchor {
+    /// Returns where along each axis the window is placed, from `0.0` at the left or top edge
+    /// to `1.0` at the right or bottom edge.
+    pub fn fraction(self) -> Vec2 {
+        match self {
+            MonitorAnchor::TopLeft => Vec2::new(0.0, 0.0),
+            MonitorAnchor::Top => Vec2::new(0.5, 0.0),
+            MonitorAnchor::TopRight => Vec2::new(1.0, 0.0),
+            MonitorAnchor::Left => Vec2::new(0.0, 0.5),
+            MonitorAnchor::Center => Vec2::new(0.5, 0.5),
+            MonitorAnchor::Right => Vec2::new(1.0, 0.5),
+            MonitorAnchor::BottomLeft => Vec2::new(0.0, 1.0),
+            MonitorAnchor::Bottom => Vec2::new(0.5, 1.0),
+            MonitorAnchor::BottomRight => Vec2::new(1.0, 1.0),
+        }
+    }
 }
 
 impl WindowPosition {
@@ -879,6 +1443,20 @@ impl WindowResolution {
         }
     }
 
+    /// Creates a new [`WindowResolution`] that is exactly `physical_width` x `physical_height`
+    /// physical pixels, regardless of the pixel density of the monitor.
+    ///
+    /// This sets a scale factor override of `1.0`, so logical and physical pixels are the same
+    /// for layout. Useful for pixel-art games that want a fixed physical size.
+    pub fn new_physical(physical_width: u32, physical_height: u32) -> Self {
+        Self {
+            physical_width,
+            physical_height,
+            scale_factor_override: Some(1.0),
+            ..Default::default()
+        }
+    }
+
     /// Builder method for adding a scale factor override to the resolution.
     pub fn with_scale_factor_override(mut self, scale_factor_override: f32) -> Self {
         self.set_scale_factor_override(Some(scale_factor_override));
@@ -977,9 +1555,11 @@ impl WindowResolution {
     #[inline]
     #[doc(hidden)]
     pub fn set_scale_factor_and_apply_to_physical_size(&mut self, scale_factor: f32) {
+        let (width, height) = (self.width(), self.height());
         self.scale_factor = scale_factor;
-        self.physical_width = (self.physical_width as f32 * scale_factor) as u32;
-        self.physical_height = (self.physical_height as f32 * scale_factor) as u32;
+        // Keep the logical size, this leaves the physical size untouched if a scale factor
+        // override is set.
+        self.set(width, height);
     }
 
     /// Set the window's scale factor, this will be used over what the backend decides.
@@ -1102,7 +1682,7 @@ impl InternalWindowState {
 /// References a screen monitor.
 ///
 /// Used when centering a [`Window`] on a monitor.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -1112,8 +1692,15 @@ impl InternalWindowState {
 pub enum MonitorSelection {
     /// Uses the current monitor of the window.
     ///
-    /// If [`WindowPosition::Centered(MonitorSelection::Current)`](WindowPosition::Centered) is used when creating a window,
-    /// the window doesn't have a monitor yet, this will fall back to [`WindowPosition::Automatic`].
+    /// When creating a window, it doesn't have a monitor yet, so this uses the monitor of the
+    /// currently focused window instead, or the primary monitor if no window is focused.
+    /// [`WindowPosition::Centered(MonitorSelection::Current)`](WindowPosition::Centered) thus
+    /// opens new windows centered on the monitor the user is working on, regardless of where the
+    /// window manager would place them.
+    ///
+    /// When changing [`Window::mode`] of an existing window, e.g. to toggle fullscreen, this is
+    /// the monitor the window is on at that moment. If the platform can't report it, the monitor
+    /// containing the largest part of the window is used, and then the primary monitor.
     Current,
     /// Uses the primary monitor of the system.
     Primary,
@@ -1121,6 +1708,13 @@ pub enum MonitorSelection {
     Index(usize),
     /// Uses a given [`crate::monitor::Monitor`] entity.
     Entity(Entity),
+    /// Uses the monitor with the specified human-readable name, as reported by
+    /// [`Monitor::name`](crate::monitor::Monitor::name).
+    ///
+    /// Names are more stable than indices across reconnects and reboots, which makes this
+    /// the preferred variant for persisted settings. Falls back to the primary monitor if
+    /// no monitor has a matching name.
+    Name(String),
 }
 
 /// Presentation mode for a [`Window`].
@@ -1222,6 +1816,57 @@ pub enum PresentMode {
     Mailbox = 5,
 }
 
+/// Latency preference of a [`Window`], used by the renderer to pick a [`PresentMode`].
+///
+/// See [`Window::desired_present_latency`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum PresentLatency {
+    /// Use [`Window::present_mode`] and [`Window::desired_maximum_frame_latency`] as they are.
+    #[default]
+    Default,
+    /// Present frames as soon as possible with a single queued frame.
+    ///
+    /// Uses [`PresentMode::AutoNoVsync`], so tearing may be observed on platforms without
+    /// [`PresentMode::Mailbox`] support.
+    Low,
+    /// Present frames in sync with the display, queueing up to three frames to absorb spikes.
+    ///
+    /// Uses [`PresentMode::Fifo`], which is supported everywhere.
+    Smooth,
+}
+
+impl PresentLatency {
+    /// Returns the present mode to configure for a window whose [`Window::present_mode`] is
+    /// `present_mode`.
+    pub fn present_mode(self, present_mode: PresentMode) -> PresentMode {
+        match self {
+            PresentLatency::Default => present_mode,
+            PresentLatency::Low => PresentMode::AutoNoVsync,
+            PresentLatency::Smooth => PresentMode::Fifo,
+        }
+    }
+
+    /// Returns the maximum frame latency to configure for a window whose
+    /// [`Window::desired_maximum_frame_latency`] is `desired`.
+    pub fn maximum_frame_latency(self, desired: Option<NonZero<u32>>) -> Option<NonZero<u32>> {
+        desired.or(match self {
+            PresentLatency::Default => None,
+            PresentLatency::Low => NonZero::new(1),
+            PresentLatency::Smooth => NonZero::new(3),
+        })
+    }
+}
+
 /// Specifies how the alpha channel of the textures should be handled during compositing, for a [`Window`].
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1263,7 +1908,7 @@ pub enum CompositeAlphaMode {
 }
 
 /// Defines the way a [`Window`] is displayed.
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -1337,6 +1982,12 @@ pub enum WindowLevel {
     Normal,
     /// The window will always be on top of [`WindowLevel::Normal`] and [`WindowLevel::AlwaysOnBottom`] windows.
     AlwaysOnTop,
+    /// The window behaves like [`WindowLevel::AlwaysOnTop`] while it is focused and like
+    /// [`WindowLevel::Normal`] otherwise.
+    ///
+    /// This is useful for companion or overlay windows that should stay on top while the user
+    /// interacts with them, but drop behind when another app is focused.
+    AlwaysOnTopWhenFocused,
 }
 
 /// The [`Window`] theme variant to use.
@@ -1355,6 +2006,111 @@ pub enum WindowTheme {
     Dark,
 }
 
+/// How the corners of a [`Window`] should be rounded.
+///
+/// ## Platform-specific
+///
+/// - Only used on Windows 11.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WindowCornerPreference {
+    /// Let the system decide whether to round the corners.
+    #[default]
+    Default,
+
+    /// Never round the corners.
+    DoNotRound,
+
+    /// Round the corners if appropriate.
+    Round,
+
+    /// Round the corners with a small radius if appropriate.
+    RoundSmall,
+}
+
+/// A color of the frame drawn by the system around a [`Window`], such as its border or title bar.
+///
+/// ## Platform-specific
+///
+/// - Only used on Windows 11.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WindowChromeColor {
+    /// Use the system default color.
+    #[default]
+    System,
+
+    /// Don't draw this part of the frame, where possible.
+    ///
+    /// The title text can't be hidden, so this behaves like [`WindowChromeColor::System`] for
+    /// [`Window::title_text_color`].
+    None,
+
+    /// Use the given color, converted to sRGB. Its alpha is ignored.
+    Color(Color),
+}
+
+/// The material drawn by the system behind a [`Window`], see [`Window::system_backdrop`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WindowBackdrop {
+    /// Let the system decide which backdrop to draw.
+    #[default]
+    Auto,
+
+    /// Don't draw a backdrop.
+    None,
+
+    /// The Mica material, meant for long-lived windows.
+    MainWindow,
+
+    /// The Acrylic material, meant for transient windows such as popups.
+    TransientWindow,
+
+    /// The Mica Alt material, meant for windows with tabs in the title bar.
+    TabbedWindow,
+
+    /// A plain blur of the content behind the window.
+    ///
+    /// Uses the Acrylic material on Windows, like [`WindowBackdrop::TransientWindow`].
+    Blur,
+}
+
+impl WindowBackdrop {
+    /// Returns whether the backdrop blurs the content behind the window on platforms that only
+    /// support a plain blur, e.g. macOS and Wayland.
+    pub const fn is_blurred(self) -> bool {
+        !matches!(self, Self::Auto | Self::None)
+    }
+}
+
 /// Specifies which [`Window`] control buttons should be enabled.
 ///
 /// ## Platform-specific
@@ -1403,6 +2159,19 @@ impl Default for EnabledButtons {
 #[derive(Component, Default)]
 pub struct ClosingWindow;
 
+/// Marker component for a [`Window`] that isn't closed automatically when requested.
+///
+/// [`close_when_requested`](crate::close_when_requested) ignores [`WindowCloseRequested`](crate::WindowCloseRequested)
+/// events for windows with this component, so the app can veto the request, e.g. to ask
+/// "are you sure you want to quit?". Despawn the window once the user confirms.
+#[derive(Component, Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, Default)
+)]
+pub struct PreventClose;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1456,4 +2225,87 @@ mod tests {
         window.set_physical_cursor_position(Some(DVec2::new(400., 600.)));
         assert!(window.physical_cursor_position().is_none());
     }
+
+    // Checks that `Window::surface_size` fits large windows into `max_surface_size` while
+    // keeping their aspect ratio.
+    #[test]
+    fn surface_size_is_clamped_to_max_surface_size() {
+        let mut window = Window {
+            resolution: WindowResolution::new(7680., 4320.),
+            ..Default::default()
+        };
+        assert_eq!(window.surface_size(), UVec2::new(7680, 4320));
+
+        window.max_surface_size = Some(UVec2::new(3840, 3840));
+        assert_eq!(window.surface_size(), UVec2::new(3840, 2160));
+
+        window.resolution = WindowResolution::new(1920., 1080.);
+        assert_eq!(window.surface_size(), UVec2::new(1920, 1080));
+    }
+
+    // Checks that `Window::letterbox` centers the locked aspect ratio in fullscreen only.
+    #[test]
+    fn letterbox_is_centered_in_fullscreen() {
+        let mut window = Window {
+            resolution: WindowResolution::new(3440., 1440.),
+            fullscreen_aspect_lock: Some(16. / 9.),
+            ..Default::default()
+        };
+        assert_eq!(window.letterbox(), None);
+
+        window.mode = WindowMode::BorderlessFullscreen(MonitorSelection::Primary);
+        assert_eq!(window.letterbox(), Some(URect::new(440, 0, 3000, 1440)));
+
+        window.resolution = WindowResolution::new(1920., 1200.);
+        assert_eq!(window.letterbox(), Some(URect::new(0, 60, 1920, 1140)));
+    }
+
+    // Checks that a physical resolution isn't scaled by the backend's scale factor on creation.
+    #[test]
+    fn physical_resolution_ignores_scale_factor() {
+        let mut resolution = WindowResolution::new_physical(640, 360);
+        resolution.set_scale_factor_and_apply_to_physical_size(2.);
+
+        assert_eq!(resolution.physical_size(), UVec2::new(640, 360));
+        assert_eq!(resolution.size(), Vec2::new(640., 360.));
+    }
+
+    #[test]
+    fn present_latency_overrides_present_mode() {
+        assert_eq!(
+            PresentLatency::Default.present_mode(PresentMode::Immediate),
+            PresentMode::Immediate
+        );
+        assert_eq!(
+            PresentLatency::Low.present_mode(PresentMode::Fifo),
+            PresentMode::AutoNoVsync
+        );
+        assert_eq!(
+            PresentLatency::Smooth.present_mode(PresentMode::Immediate),
+            PresentMode::Fifo
+        );
+
+        assert_eq!(PresentLatency::Default.maximum_frame_latency(None), None);
+        assert_eq!(
+            PresentLatency::Low.maximum_frame_latency(None),
+            NonZero::new(1)
+        );
+        assert_eq!(
+            PresentLatency::Smooth.maximum_frame_latency(NonZero::new(2)),
+            NonZero::new(2)
+        );
+    }
+
+    #[test]
+    fn releasing_grab_shows_cursor_again() {
+        let mut cursor_options = CursorOptions {
+            grab_mode: CursorGrabMode::Locked,
+            hide_while_grabbed: true,
+            ..Default::default()
+        };
+        assert!(!cursor_options.is_cursor_shown());
+
+        cursor_options.grab_mode = CursorGrabMode::None;
+        assert!(cursor_options.is_cursor_shown());
+    }
 }
@@ -16,8 +16,12 @@ use bevy_a11y::{
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{entity::hash_map::EntityHashMap, prelude::*};
+#[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "windows"))]
+use bevy_window::WindowFocused;
 use bevy_window::{PrimaryWindow, Window, WindowClosed};
 
+use crate::WinitWindows;
+
 /// Maps window entities to their `AccessKit` [`Adapter`]s.
 #[derive(Default, Deref, DerefMut)]
 pub struct AccessKitAdapters(pub EntityHashMap<Adapter>);
@@ -141,6 +145,22 @@ pub(crate) fn prepare_accessibility_for_window(
     handlers.insert(entity, action_request_handler);
 }
 
+/// Updates the label of the root window node of a secondary window's `AccessKit` tree.
+///
+/// Only the primary window has child nodes, which [`update_adapter`] keeps when relabeling it.
+fn update_secondary_window_name(adapter: &mut Adapter, entity: Entity, name: &str) {
+    adapter.update_if_active(|| {
+        let mut window_node = Node::new(Role::Window);
+        window_node.set_label(name);
+        let node_id = NodeId(entity.to_bits());
+        TreeUpdate {
+            nodes: vec![(node_id, window_node)],
+            tree: None,
+            focus: node_id,
+        }
+    });
+}
+
 fn window_closed(
     mut adapters: NonSendMut<AccessKitAdapters>,
     mut handlers: ResMut<WinitActionRequestHandlers>,
@@ -173,7 +193,9 @@ fn should_update_accessibility_nodes(
 
 fn update_accessibility_nodes(
     mut adapters: NonSendMut<AccessKitAdapters>,
+    mut winit_windows: NonSendMut<WinitWindows>,
     focus: Option<Res<InputFocus>>,
+    windows: Query<&Window>,
     primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
     nodes: Query<(
         Entity,
@@ -183,7 +205,24 @@ fn update_accessibility_nodes(
     )>,
     node_entities: Query<Entity, With<AccessibilityNode>>,
 ) {
-    let Ok((primary_window_id, primary_window)) = primary_window.get_single() else {
+    let primary_window = primary_window.get_single().ok();
+    let renamed: Vec<_> = winit_windows.renamed_windows.drain().collect();
+    let mut primary_renamed = false;
+    for entity in renamed {
+        if primary_window.is_some_and(|(primary_window_id, _)| primary_window_id == entity) {
+            primary_renamed = true;
+            continue;
+        }
+        let (Some(adapter), Ok(window)) = (adapters.get_mut(&entity), windows.get(entity)) else {
+            continue;
+        };
+        let name = winit_windows
+            .accessibility_name(entity)
+            .unwrap_or(&window.title);
+        update_secondary_window_name(adapter, entity, name);
+    }
+
+    let Some((primary_window_id, primary_window)) = primary_window else {
         return;
     };
     let Some(adapter) = adapters.get_mut(&primary_window_id) else {
@@ -192,7 +231,7 @@ fn update_accessibility_nodes(
     let Some(focus) = focus else {
         return;
     };
-    if focus.is_changed() || !nodes.is_empty() {
+    if focus.is_changed() || !nodes.is_empty() || primary_renamed {
         // Don't panic if the focused entity does not currently exist
         // It's probably waiting to be spawned
         if let Some(focused_entity) = focus.0 {
@@ -201,11 +240,15 @@ fn update_accessibility_nodes(
             }
         }
 
+        let name = winit_windows
+            .accessibility_name(primary_window_id)
+            .unwrap_or(&primary_window.title);
         adapter.update_if_active(|| {
             update_adapter(
                 nodes,
                 node_entities,
                 primary_window,
+                name,
                 primary_window_id,
                 focus,
             )
@@ -222,6 +265,7 @@ fn update_adapter(
     )>,
     node_entities: Query<Entity, With<AccessibilityNode>>,
     primary_window: &Window,
+    name: &str,
     primary_window_id: Entity,
     focus: Res<InputFocus>,
 ) -> TreeUpdate {
@@ -236,8 +280,7 @@ fn update_adapter(
     }
     let mut window_node = Node::new(Role::Window);
     if primary_window.focused {
-        let title = primary_window.title.clone();
-        window_node.set_label(title.into_boxed_str());
+        window_node.set_label(name);
     }
     window_node.set_children(window_children);
     let node_id = NodeId(primary_window_id.to_bits());
@@ -283,6 +326,112 @@ fn add_children_nodes(
     }
 }
 
+/// The user's accessibility preferences from the operating system, e.g. to disable screen
+/// shake when the user prefers reduced motion.
+///
+/// This is read when the app starts, unless the app inserted it first, and on platforms that
+/// expose the preferences, again whenever a window gains focus, since changed settings aren't
+/// reported. Each preference is `false` if the user has none or if the platform doesn't expose
+/// it, and apps can also set this resource from their own settings.
+///
+/// ## Platform-specific
+///
+/// - **Web:** Read from the `prefers-reduced-motion`, `prefers-contrast` and
+///   `prefers-reduced-transparency` media queries.
+/// - **macOS:** Read from the `accessibilityDisplayShouldReduceMotion`,
+///   `accessibilityDisplayShouldIncreaseContrast` and
+///   `accessibilityDisplayShouldReduceTransparency` properties of `NSWorkspace`.
+/// - **Windows:** Read from the registry: the high contrast flags, the `MinAnimate` window
+///   metric and the `EnableTransparency` theme setting.
+/// - **Others:** Unsupported, all preferences are `false`. Neither `winit` nor `AccessKit`
+///   expose them.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccessibilityPreferences {
+    /// Whether the user prefers less motion, e.g. no screen shake or parallax.
+    pub reduced_motion: bool,
+    /// Whether the user prefers more contrast between colors.
+    pub high_contrast: bool,
+    /// Whether the user prefers opaque instead of translucent surfaces.
+    pub reduced_transparency: bool,
+}
+
+impl AccessibilityPreferences {
+    /// Reads the preferences from the operating system.
+    pub fn from_os() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            Self {
+                reduced_motion: media_query_matches("(prefers-reduced-motion: reduce)"),
+                high_contrast: media_query_matches("(prefers-contrast: more)"),
+                reduced_transparency: media_query_matches("(prefers-reduced-transparency: reduce)"),
+            }
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let workspace = objc2_app_kit::NSWorkspace::sharedWorkspace();
+            Self {
+                reduced_motion: workspace.accessibilityDisplayShouldReduceMotion(),
+                high_contrast: workspace.accessibilityDisplayShouldIncreaseContrast(),
+                reduced_transparency: workspace.accessibilityDisplayShouldReduceTransparency(),
+            }
+        }
+        #[cfg(target_os = "windows")]
+        {
+            // `HCF_HIGHCONTRASTON` is the lowest bit of the high contrast flags.
+            let high_contrast =
+                read_registry_value(r"Control Panel\Accessibility\HighContrast", "Flags")
+                    .is_some_and(|flags| flags & 1 != 0);
+            let reduced_motion =
+                read_registry_value(r"Control Panel\Desktop\WindowMetrics", "MinAnimate")
+                    == Some(0);
+            let reduced_transparency = read_registry_value(
+                r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+                "EnableTransparency",
+            ) == Some(0);
+            Self {
+                reduced_motion,
+                high_contrast,
+                reduced_transparency,
+            }
+        }
+        #[cfg(not(any(target_arch = "wasm32", target_os = "macos", target_os = "windows")))]
+        Self::default()
+    }
+}
+
+/// Reads a numeric value of a key of the current user's registry hive.
+///
+/// Some of the settings are stored as numbers in strings, so those are parsed as well.
+#[cfg(target_os = "windows")]
+fn read_registry_value(key: &str, name: &str) -> Option<u32> {
+    let key = winreg::RegKey::predef(winreg::enums::HKEY_CURRENT_USER)
+        .open_subkey(key)
+        .ok()?;
+    key.get_value::<u32, _>(name)
+        .ok()
+        .or_else(|| key.get_value::<String, _>(name).ok()?.trim().parse().ok())
+}
+
+/// Returns whether the CSS media query matches in the current browser window.
+#[cfg(target_arch = "wasm32")]
+fn media_query_matches(query: &str) -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(query).ok().flatten())
+        .is_some_and(|list| list.matches())
+}
+
+/// Reads the [`AccessibilityPreferences`] again when a window gains focus, since the user may
+/// have changed them while the app was in the background.
+#[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "windows"))]
+fn refresh_accessibility_preferences(
+    mut focus_events: EventReader<WindowFocused>,
+    mut preferences: ResMut<AccessibilityPreferences>,
+) {
+    if focus_events.read().any(|event| event.focused) {
+        preferences.set_if_neq(AccessibilityPreferences::from_os());
+    }
+}
+
 /// Implements winit-specific `AccessKit` functionality.
 pub struct AccessKitPlugin;
 
@@ -302,5 +451,13 @@ impl Plugin for AccessKitPlugin {
                 )
                     .in_set(AccessibilitySystem::Update),
             );
+
+        // Preferences the app inserted from its own settings are kept.
+        if !app.world().contains_resource::<AccessibilityPreferences>() {
+            app.insert_resource(AccessibilityPreferences::from_os());
+        }
+
+        #[cfg(any(target_arch = "wasm32", target_os = "macos", target_os = "windows"))]
+        app.add_systems(bevy_app::PreUpdate, refresh_accessibility_preferences);
     }
 }
@@ -42,18 +42,22 @@ pub fn convert_mouse_button(mouse_button: winit::event::MouseButton) -> MouseBut
     }
 }
 
+pub fn convert_touch_phase(phase: winit::event::TouchPhase) -> TouchPhase {
+    match phase {
+        winit::event::TouchPhase::Started => TouchPhase::Started,
+        winit::event::TouchPhase::Moved => TouchPhase::Moved,
+        winit::event::TouchPhase::Ended => TouchPhase::Ended,
+        winit::event::TouchPhase::Cancelled => TouchPhase::Canceled,
+    }
+}
+
 pub fn convert_touch_input(
     touch_input: winit::event::Touch,
     location: winit::dpi::LogicalPosition<f64>,
     window_entity: Entity,
 ) -> TouchInput {
     TouchInput {
-        phase: match touch_input.phase {
-            winit::event::TouchPhase::Started => TouchPhase::Started,
-            winit::event::TouchPhase::Moved => TouchPhase::Moved,
-            winit::event::TouchPhase::Ended => TouchPhase::Ended,
-            winit::event::TouchPhase::Cancelled => TouchPhase::Canceled,
-        },
+        phase: convert_touch_phase(touch_input.phase),
         position: Vec2::new(location.x as f32, location.y as f32),
         window: window_entity,
         force: touch_input.force.map(|f| match f {
@@ -666,15 +670,22 @@ pub fn convert_system_cursor_icon(cursor_icon: SystemCursorIcon) -> winit::windo
         SystemCursorIcon::NwseResize => winit::window::CursorIcon::NwseResize,
         SystemCursorIcon::ColResize => winit::window::CursorIcon::ColResize,
         SystemCursorIcon::RowResize => winit::window::CursorIcon::RowResize,
-        _ => winit::window::CursorIcon::Default,
+        SystemCursorIcon::Default => winit::window::CursorIcon::Default,
     }
 }
 
-pub fn convert_window_level(window_level: WindowLevel) -> winit::window::WindowLevel {
+/// Converts a [`WindowLevel`] to the level `winit` should use for a window with the given focus.
+pub fn convert_window_level(
+    window_level: WindowLevel,
+    focused: bool,
+) -> winit::window::WindowLevel {
     match window_level {
         WindowLevel::AlwaysOnBottom => winit::window::WindowLevel::AlwaysOnBottom,
-        WindowLevel::Normal => winit::window::WindowLevel::Normal,
         WindowLevel::AlwaysOnTop => winit::window::WindowLevel::AlwaysOnTop,
+        WindowLevel::AlwaysOnTopWhenFocused if focused => winit::window::WindowLevel::AlwaysOnTop,
+        WindowLevel::Normal | WindowLevel::AlwaysOnTopWhenFocused => {
+            winit::window::WindowLevel::Normal
+        }
     }
 }
 
@@ -718,3 +729,106 @@ pub fn convert_resize_direction(resize_direction: CompassOctant) -> winit::windo
         CompassOctant::SouthEast => winit::window::ResizeDirection::SouthEast,
     }
 }
+
+#[cfg(target_os = "windows")]
+pub fn convert_corner_preference(
+    corner_preference: bevy_window::WindowCornerPreference,
+) -> winit::platform::windows::CornerPreference {
+    use bevy_window::WindowCornerPreference;
+    use winit::platform::windows::CornerPreference;
+    match corner_preference {
+        WindowCornerPreference::Default => CornerPreference::Default,
+        WindowCornerPreference::DoNotRound => CornerPreference::DoNotRound,
+        WindowCornerPreference::Round => CornerPreference::Round,
+        WindowCornerPreference::RoundSmall => CornerPreference::RoundSmall,
+    }
+}
+
+/// Converts a frame color to the form `winit` expects, `None` meaning no color at all.
+#[cfg(target_os = "windows")]
+pub fn convert_chrome_color(
+    color: bevy_window::WindowChromeColor,
+) -> Option<winit::platform::windows::Color> {
+    use bevy_color::ColorToPacked;
+    use bevy_window::WindowChromeColor;
+    use winit::platform::windows::Color;
+    match color {
+        WindowChromeColor::System => Some(Color::SYSTEM_DEFAULT),
+        WindowChromeColor::None => None,
+        WindowChromeColor::Color(color) => {
+            let [r, g, b, _] = color.to_srgba().to_u8_array();
+            Some(Color::from_rgb(r, g, b))
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn convert_system_backdrop(
+    backdrop: bevy_window::WindowBackdrop,
+) -> winit::platform::windows::BackdropType {
+    use bevy_window::WindowBackdrop;
+    use winit::platform::windows::BackdropType;
+    match backdrop {
+        WindowBackdrop::Auto => BackdropType::Auto,
+        WindowBackdrop::None => BackdropType::None,
+        WindowBackdrop::MainWindow => BackdropType::MainWindow,
+        WindowBackdrop::TransientWindow | WindowBackdrop::Blur => BackdropType::TransientWindow,
+        WindowBackdrop::TabbedWindow => BackdropType::TabbedWindow,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_platform_support::collections::HashSet;
+
+    #[test]
+    fn every_system_cursor_icon_maps_to_a_distinct_winit_icon() {
+        let icons = [
+            SystemCursorIcon::Default,
+            SystemCursorIcon::ContextMenu,
+            SystemCursorIcon::Help,
+            SystemCursorIcon::Pointer,
+            SystemCursorIcon::Progress,
+            SystemCursorIcon::Wait,
+            SystemCursorIcon::Cell,
+            SystemCursorIcon::Crosshair,
+            SystemCursorIcon::Text,
+            SystemCursorIcon::VerticalText,
+            SystemCursorIcon::Alias,
+            SystemCursorIcon::Copy,
+            SystemCursorIcon::Move,
+            SystemCursorIcon::NoDrop,
+            SystemCursorIcon::NotAllowed,
+            SystemCursorIcon::Grab,
+            SystemCursorIcon::Grabbing,
+            SystemCursorIcon::EResize,
+            SystemCursorIcon::NResize,
+            SystemCursorIcon::NeResize,
+            SystemCursorIcon::NwResize,
+            SystemCursorIcon::SResize,
+            SystemCursorIcon::SeResize,
+            SystemCursorIcon::SwResize,
+            SystemCursorIcon::WResize,
+            SystemCursorIcon::EwResize,
+            SystemCursorIcon::NsResize,
+            SystemCursorIcon::NeswResize,
+            SystemCursorIcon::NwseResize,
+            SystemCursorIcon::ColResize,
+            SystemCursorIcon::RowResize,
+            SystemCursorIcon::AllScroll,
+            SystemCursorIcon::ZoomIn,
+            SystemCursorIcon::ZoomOut,
+        ];
+
+        let converted: HashSet<_> = icons
+            .iter()
+            .map(|icon| convert_system_cursor_icon(*icon))
+            .collect();
+        assert_eq!(converted.len(), icons.len());
+        assert_eq!(
+            convert_system_cursor_icon(SystemCursorIcon::Default),
+            winit::window::CursorIcon::Default
+        );
+    }
+}
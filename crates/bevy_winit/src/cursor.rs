@@ -8,7 +8,7 @@ use crate::{
 use crate::{
     custom_cursor::{
         calculate_effective_rect, extract_and_transform_rgba_pixels, extract_rgba_pixels,
-        transform_hotspot, CustomCursorPlugin,
+        scale_hotspot, scale_rgba_pixels, transform_hotspot, CustomCursorPlugin,
     },
     state::{CustomCursorCache, CustomCursorCacheKey},
     WinitCustomCursor,
@@ -16,8 +16,6 @@ use crate::{
 use bevy_app::{App, Last, Plugin};
 #[cfg(feature = "custom_cursor")]
 use bevy_asset::Assets;
-#[cfg(feature = "custom_cursor")]
-use bevy_ecs::system::Res;
 use bevy_ecs::{
     change_detection::DetectChanges,
     component::Component,
@@ -29,9 +27,13 @@ use bevy_ecs::{
     world::{OnRemove, Ref},
 };
 #[cfg(feature = "custom_cursor")]
+use bevy_ecs::{event::EventReader, system::Res};
+#[cfg(feature = "custom_cursor")]
 use bevy_image::{Image, TextureAtlasLayout};
 use bevy_platform_support::collections::HashSet;
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "custom_cursor")]
+use bevy_window::WindowScaleFactorChanged;
 use bevy_window::{SystemCursorIcon, Window};
 #[cfg(feature = "custom_cursor")]
 use tracing::warn;
@@ -79,11 +81,27 @@ impl From<SystemCursorIcon> for CursorIcon {
 fn update_cursors(
     mut commands: Commands,
     windows: Query<(Entity, Ref<CursorIcon>), With<Window>>,
+    #[cfg(feature = "custom_cursor")] window_components: Query<&Window>,
     #[cfg(feature = "custom_cursor")] cursor_cache: Res<CustomCursorCache>,
     #[cfg(feature = "custom_cursor")] images: Res<Assets<Image>>,
     #[cfg(feature = "custom_cursor")] texture_atlases: Res<Assets<TextureAtlasLayout>>,
+    #[cfg(feature = "custom_cursor")] mut scale_factor_changed: EventReader<
+        WindowScaleFactorChanged,
+    >,
     mut queue: Local<HashSet<Entity>>,
 ) {
+    // Cursors scaled with their window have to be recreated at the new scale factor.
+    #[cfg(feature = "custom_cursor")]
+    for event in scale_factor_changed.read() {
+        if let Ok((_, cursor)) = windows.get(event.window) {
+            if let CursorIcon::Custom(CustomCursor::Image(c)) = cursor.as_ref() {
+                if c.scale_with_window {
+                    queue.insert(event.window);
+                }
+            }
+        }
+    }
+
     for (entity, cursor) in windows.iter() {
         if !(queue.remove(&entity) || cursor.is_changed()) {
             continue;
@@ -99,8 +117,15 @@ fn update_cursors(
                     flip_y,
                     rect,
                     hotspot,
+                    scale_with_window,
                 } = c;
 
+                let scale_factor = scale_with_window.then(|| {
+                    window_components
+                        .get(entity)
+                        .map_or(1.0, |window| window.resolution.scale_factor())
+                });
+
                 let cache_key = CustomCursorCacheKey::Image {
                     id: handle.id(),
                     texture_atlas_layout_id: texture_atlas.as_ref().map(|a| a.layout.id()),
@@ -108,6 +133,7 @@ fn update_cursors(
                     flip_x: *flip_x,
                     flip_y: *flip_y,
                     rect: *rect,
+                    scale_factor: scale_factor.map(f32::to_bits),
                 };
 
                 if cursor_cache.0.contains_key(&cache_key) {
@@ -138,12 +164,22 @@ fn update_cursors(
                         continue;
                     };
 
+                    let (rgba, width, height, hotspot) = match scale_factor {
+                        Some(scale_factor) => {
+                            let (rgba, width, height) = scale_rgba_pixels(
+                                &rgba,
+                                rect.width() as u16,
+                                rect.height() as u16,
+                                scale_factor,
+                            );
+                            let hotspot = scale_hotspot(hotspot, scale_factor, (width, height));
+                            (rgba, width, height, hotspot)
+                        }
+                        None => (rgba, rect.width() as u16, rect.height() as u16, hotspot),
+                    };
+
                     let source = match WinitCustomCursor::from_rgba(
-                        rgba,
-                        rect.width() as u16,
-                        rect.height() as u16,
-                        hotspot.0,
-                        hotspot.1,
+                        rgba, width, height, hotspot.0, hotspot.1,
                     ) {
                         Ok(source) => source,
                         Err(err) => {
@@ -40,6 +40,15 @@ pub struct CustomCursorImage {
     /// to adjust this field to account for the flip because it is adjusted
     /// automatically.
     pub hotspot: (u16, u16),
+    /// Whether the image and `hotspot` are in logical pixels.
+    ///
+    /// If true, the image is scaled by the window's scale factor and the
+    /// hotspot is scaled along with it, so the cursor keeps its apparent size
+    /// and click point on high DPI monitors. The cursor is recreated when the
+    /// window's scale factor changes, e.g. when it moves to another monitor.
+    ///
+    /// If false, the image is used at its native size in physical pixels.
+    pub scale_with_window: bool,
 }
 
 #[cfg(all(target_family = "wasm", target_os = "unknown"))]
@@ -192,6 +201,49 @@ pub(crate) fn extract_and_transform_rgba_pixels(
     Some(sub_image_data)
 }
 
+/// Scales RGBA pixel data of the given size by `scale_factor`, using nearest
+/// neighbor sampling.
+///
+/// Returns the scaled data and its size, which is at least one pixel in each
+/// dimension.
+pub(crate) fn scale_rgba_pixels(
+    rgba: &[u8],
+    width: u16,
+    height: u16,
+    scale_factor: f32,
+) -> (Vec<u8>, u16, u16) {
+    let scaled_width = ops::round(width as f32 * scale_factor).clamp(1.0, u16::MAX as f32) as u16;
+    let scaled_height = ops::round(height as f32 * scale_factor).clamp(1.0, u16::MAX as f32) as u16;
+
+    let mut scaled = Vec::with_capacity(scaled_width as usize * scaled_height as usize * 4);
+    for y in 0..scaled_height as usize {
+        let src_y = (y * height as usize / scaled_height as usize).min(height as usize - 1);
+        for x in 0..scaled_width as usize {
+            let src_x = (x * width as usize / scaled_width as usize).min(width as usize - 1);
+            let index = (src_y * width as usize + src_x) * 4;
+            scaled.extend_from_slice(&rgba[index..index + 4]);
+        }
+    }
+
+    (scaled, scaled_width, scaled_height)
+}
+
+/// Scales the `hotspot` coordinates by `scale_factor`, keeping them within an
+/// image of the given scaled size.
+pub(crate) fn scale_hotspot(
+    hotspot: (u16, u16),
+    scale_factor: f32,
+    scaled_size: (u16, u16),
+) -> (u16, u16) {
+    let scale = |coordinate: u16, size: u16| {
+        (ops::floor(coordinate as f32 * scale_factor) as u16).min(size.saturating_sub(1))
+    };
+    (
+        scale(hotspot.0, scaled_size.0),
+        scale(hotspot.1, scaled_size.1),
+    )
+}
+
 /// Transforms the `hotspot` coordinates based on whether the image is flipped
 /// or not. The `rect` is used to determine the image's dimensions.
 pub(crate) fn transform_hotspot(
@@ -601,4 +653,19 @@ mod tests {
         test((10, 20), true, true, rect, (89, 179)); // flip both
         test((0, 0), true, true, rect, (99, 199)); // flip both (bounds check)
     }
+
+    #[test]
+    fn test_scale_cursor_for_hidpi() {
+        // 2x1 image: Red, Green
+        let rgba = [255, 0, 0, 255, 0, 255, 0, 255];
+        let (scaled, width, height) = scale_rgba_pixels(&rgba, 2, 1, 2.0);
+        assert_eq!((width, height), (4, 2));
+        assert_eq!(&scaled[0..8], &[255, 0, 0, 255, 255, 0, 0, 255]);
+        assert_eq!(&scaled[8..16], &[0, 255, 0, 255, 0, 255, 0, 255]);
+        assert_eq!(&scaled[16..32], &scaled[0..16]);
+
+        assert_eq!(scale_hotspot((1, 0), 2.0, (width, height)), (2, 0));
+        assert_eq!(scale_hotspot((1, 0), 1.5, (3, 2)), (1, 0));
+        assert_eq!(scale_hotspot((2, 1), 2.0, (width, height)), (3, 1));
+    }
 }
@@ -17,15 +17,25 @@ extern crate alloc;
 use bevy_derive::Deref;
 use bevy_reflect::prelude::ReflectDefault;
 use bevy_reflect::Reflect;
-use bevy_window::{RawHandleWrapperHolder, WindowEvent};
+use bevy_window::{RawHandleWrapper, RawHandleWrapperHolder, WindowEvent};
 use core::marker::PhantomData;
 use winit::{event_loop::EventLoop, window::WindowId};
 
 use bevy_a11y::AccessibilityRequested;
 use bevy_app::{App, Last, Plugin};
 use bevy_ecs::prelude::*;
-use bevy_window::{exit_on_all_closed, Window, WindowCreated};
-use system::{changed_windows, check_keyboard_focus_lost, despawn_windows};
+use bevy_window::{
+    exit_on_all_closed, CursorGrabChanged, Monitor, Window, WindowCreated, WindowPresentation,
+};
+use system::{
+    apply_held_resizes, apply_settled_scale_factors, changed_windows, check_keyboard_focus_lost,
+    close_confirmed_windows, close_monitor_identifiers, close_splash_windows, despawn_windows,
+    detect_window_activation_changes, detect_window_monitor_changes, follow_primary_monitor,
+    preserve_window_aspect_ratios, raise_anchored_windows, reapply_anchored_window_positions,
+    recenter_relative_mouse_cursors, remember_window_geometry, restore_gamma_on_exit,
+    retry_pending_grabs, sync_resize_constraints, sync_window_modes, sync_window_updates,
+    update_focused_window_levels, update_modal_windows,
+};
 pub use system::{create_monitors, create_windows};
 #[cfg(all(target_family = "wasm", target_os = "unknown"))]
 pub use winit::platform::web::CustomCursorExtWebSys;
@@ -34,12 +44,12 @@ pub use winit::{
     window::{CustomCursor as WinitCustomCursor, CustomCursorSource},
 };
 pub use winit_config::*;
+pub use winit_monitors::{MonitorInfo, WinitMonitors};
 pub use winit_windows::*;
 
 use crate::{
     accessibility::{AccessKitAdapters, AccessKitPlugin, WinitActionRequestHandlers},
     state::winit_runner,
-    winit_monitors::WinitMonitors,
 };
 
 pub mod accessibility;
@@ -47,11 +57,24 @@ mod converters;
 pub mod cursor;
 #[cfg(feature = "custom_cursor")]
 mod custom_cursor;
+#[cfg(target_os = "macos")]
+mod macos;
 mod state;
 mod system;
 mod winit_config;
 mod winit_monitors;
 mod winit_windows;
+#[cfg(all(
+    feature = "x11",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+mod x11;
 
 /// A [`Plugin`] that uses `winit` to create and manage windows, and receive window and input
 /// events.
@@ -77,9 +100,28 @@ pub struct WinitPlugin<T: Event = WakeUp> {
     /// Only works on Linux (X11/Wayland) and Windows.
     /// This field is ignored on other platforms.
     pub run_on_any_thread: bool,
+    /// The windowing backend to prefer when both X11 and Wayland are enabled and available.
+    ///
+    /// If the preferred backend isn't available at runtime, `winit` picks one as usual. The
+    /// backend that was actually selected is logged on startup.
+    ///
+    /// # Supported platforms
+    ///
+    /// Only works on Linux with the `x11` and `wayland` features enabled.
+    /// This field is ignored on other platforms.
+    pub preferred_backend: Option<WinitBackend>,
     marker: PhantomData<T>,
 }
 
+/// A windowing backend that [`WinitPlugin::preferred_backend`] can prefer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinitBackend {
+    /// The X Window System, available if the `DISPLAY` environment variable is set.
+    X11,
+    /// Wayland, available if the `WAYLAND_DISPLAY` or `WAYLAND_SOCKET` environment variable is set.
+    Wayland,
+}
+
 impl<T: Event> Plugin for WinitPlugin<T> {
     fn name(&self) -> &str {
         "bevy_winit::WinitPlugin"
@@ -107,6 +149,29 @@ impl<T: Event> Plugin for WinitPlugin<T> {
             event_loop_builder.with_any_thread(self.run_on_any_thread);
         }
 
+        // The event loop can't be recreated if building it fails, so only force a backend that
+        // appears to be available, the same way `winit` detects it.
+        #[cfg(all(target_os = "linux", feature = "x11", feature = "wayland"))]
+        match self.preferred_backend {
+            Some(WinitBackend::X11) if std::env::var_os("DISPLAY").is_some() => {
+                use winit::platform::x11::EventLoopBuilderExtX11;
+                event_loop_builder.with_x11();
+            }
+            Some(WinitBackend::Wayland)
+                if std::env::var_os("WAYLAND_DISPLAY").is_some()
+                    || std::env::var_os("WAYLAND_SOCKET").is_some() =>
+            {
+                use winit::platform::wayland::EventLoopBuilderExtWayland;
+                event_loop_builder.with_wayland();
+            }
+            Some(backend) => {
+                tracing::warn!(
+                    "Preferred backend {backend:?} is unavailable, falling back to the default"
+                );
+            }
+            None => {}
+        }
+
         #[cfg(target_os = "windows")]
         {
             use winit::platform::windows::EventLoopBuilderExtWindows;
@@ -123,19 +188,38 @@ impl<T: Event> Plugin for WinitPlugin<T> {
         app.init_non_send_resource::<WinitWindows>()
             .init_resource::<WinitMonitors>()
             .init_resource::<WinitSettings>()
+            .init_resource::<ScaleFactorHysteresis>()
             .add_event::<RawWinitWindowEvent>()
             .set_runner(winit_runner::<T>)
             .add_systems(
                 Last,
                 (
+                    (sync_resize_constraints, sync_window_updates).chain(),
                     // `exit_on_all_closed` only checks if windows exist but doesn't access data,
                     // so we don't need to care about its ordering relative to `changed_windows`
                     changed_windows.ambiguous_with(exit_on_all_closed),
+                    close_confirmed_windows,
                     despawn_windows,
+                    sync_window_modes,
                     check_keyboard_focus_lost,
+                    detect_window_activation_changes,
+                    update_focused_window_levels,
+                    raise_anchored_windows,
+                    update_modal_windows,
+                    retry_pending_grabs,
+                    follow_primary_monitor,
+                    reapply_anchored_window_positions,
+                    detect_window_monitor_changes,
+                    remember_window_geometry,
+                    preserve_window_aspect_ratios,
+                    (apply_settled_scale_factors, apply_held_resizes).chain(),
+                    close_splash_windows,
+                    close_monitor_identifiers,
+                    recenter_relative_mouse_cursors,
                 )
                     .chain(),
-            );
+            )
+            .add_systems(Last, restore_gamma_on_exit);
 
         app.add_plugins(AccessKitPlugin);
         app.add_plugins(cursor::CursorPlugin);
@@ -144,6 +228,28 @@ impl<T: Event> Plugin for WinitPlugin<T> {
             .build()
             .expect("Failed to build event loop");
 
+        #[cfg(all(target_os = "linux", feature = "wayland"))]
+        {
+            use winit::platform::wayland::EventLoopExtWayland;
+            if event_loop.is_wayland() {
+                tracing::info!("Using the Wayland windowing backend");
+                app.world_mut()
+                    .non_send_resource_mut::<WinitWindows>()
+                    .backend = Some(WinitBackend::Wayland);
+            }
+        }
+        #[cfg(all(target_os = "linux", feature = "x11"))]
+        {
+            use winit::platform::x11::EventLoopExtX11;
+            if event_loop.is_x11() {
+                tracing::info!("Using the X11 windowing backend");
+                app.world_mut()
+                    .non_send_resource_mut::<WinitWindows>()
+                    .backend = Some(WinitBackend::X11);
+                app.world_mut().resource_mut::<WinitMonitors>().is_x11 = true;
+            }
+        }
+
         // `winit`'s windows are bound to the event loop that created them, so the event loop must
         // be inserted as a resource here to pass it onto the runner.
         app.insert_non_send_resource(event_loop);
@@ -200,16 +306,26 @@ pub type CreateWindowParams<'w, 's, F = ()> = (
             Entity,
             &'static mut Window,
             Option<&'static RawHandleWrapperHolder>,
+            Has<RawHandleWrapper>,
         ),
         F,
     >,
     EventWriter<'w, WindowCreated>,
+    EventWriter<'w, CursorGrabChanged>,
     NonSendMut<'w, WinitWindows>,
     NonSendMut<'w, AccessKitAdapters>,
     ResMut<'w, WinitActionRequestHandlers>,
     Res<'w, AccessibilityRequested>,
     Res<'w, WinitMonitors>,
+    Option<Res<'w, WindowAttributesHook>>,
+    Option<Res<'w, DefaultWindowIcon>>,
+    Option<Res<'w, WindowPresentation>>,
 );
 
 /// The parameters of the [`create_monitors`] system.
-pub type CreateMonitorParams<'w, 's> = (Commands<'w, 's>, ResMut<'w, WinitMonitors>);
+pub type CreateMonitorParams<'w, 's> = (
+    Commands<'w, 's>,
+    ResMut<'w, WinitMonitors>,
+    Option<Res<'w, VirtualMonitor>>,
+    Query<'w, 's, &'static Monitor>,
+);
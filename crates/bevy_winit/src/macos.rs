@@ -0,0 +1,53 @@
+//! `AppKit` calls for window features that `winit` doesn't expose.
+
+use objc2::{rc::Retained, MainThreadMarker};
+use objc2_app_kit::{NSApplication, NSWindow, NSWindowCollectionBehavior};
+use objc2_foundation::NSString;
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use winit::window::Window as WinitWindow;
+
+/// Returns the `NSWindow` of a window.
+///
+/// `winit` only hands out the content view, so this looks for the app window that hosts it.
+/// Returns `None` when called off the main thread.
+fn ns_window(window: &WinitWindow) -> Option<Retained<NSWindow>> {
+    let RawWindowHandle::AppKit(handle) = window.window_handle().ok()?.as_raw() else {
+        return None;
+    };
+    let ns_view = handle.ns_view.as_ptr().cast_const();
+    let app = NSApplication::sharedApplication(MainThreadMarker::new()?);
+    app.windows().iter().find(|ns_window| {
+        ns_window
+            .contentView()
+            .is_some_and(|view| Retained::as_ptr(&view).cast() == ns_view)
+    })
+}
+
+/// Makes a window join all spaces, or only the one it is shown on.
+pub(crate) fn set_visible_on_all_workspaces(window: &WinitWindow, visible: bool) -> bool {
+    let Some(ns_window) = ns_window(window) else {
+        return false;
+    };
+    let mut behavior = ns_window.collectionBehavior();
+    behavior.set(NSWindowCollectionBehavior::CanJoinAllSpaces, visible);
+    // A window can't both join all spaces and move to the active one.
+    if visible {
+        behavior.remove(NSWindowCollectionBehavior::MoveToActiveSpace);
+    }
+    ns_window.setCollectionBehavior(behavior);
+    true
+}
+
+/// Sets the badge label of the app's dock tile, or removes it.
+///
+/// Returns `false` when called off the main thread.
+pub(crate) fn set_badge_label(label: Option<&str>) -> bool {
+    let Some(mtm) = MainThreadMarker::new() else {
+        return false;
+    };
+    let label = label.map(NSString::from_str);
+    NSApplication::sharedApplication(mtm)
+        .dockTile()
+        .setBadgeLabel(label.as_deref());
+    true
+}
@@ -15,11 +15,12 @@ use bevy_image::{Image, TextureAtlasLayout};
 use bevy_input::{
     gestures::*,
     mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    touch::TouchPhase,
 };
 use bevy_log::{error, trace, warn};
 #[cfg(feature = "custom_cursor")]
 use bevy_math::URect;
-use bevy_math::{ivec2, DVec2, Vec2};
+use bevy_math::{ivec2, DVec2, UVec2, Vec2};
 #[cfg(feature = "custom_cursor")]
 use bevy_platform_support::collections::HashMap;
 use bevy_platform_support::time::Instant;
@@ -30,28 +31,33 @@ use core::marker::PhantomData;
 use winit::platform::web::EventLoopExtWebSys;
 use winit::{
     application::ApplicationHandler,
-    dpi::PhysicalSize,
+    dpi::{LogicalPosition, PhysicalSize},
     event,
     event::{DeviceEvent, DeviceId, StartCause, WindowEvent},
-    event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
+    event_loop::{ActiveEventLoop, ControlFlow, DeviceEvents, EventLoop},
     window::WindowId,
 };
 
 use bevy_window::{
-    AppLifecycle, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, Ime, RequestRedraw,
-    Window, WindowBackendScaleFactorChanged, WindowCloseRequested, WindowDestroyed,
-    WindowEvent as BevyWindowEvent, WindowFocused, WindowMoved, WindowOccluded, WindowResized,
-    WindowScaleFactorChanged, WindowThemeChanged,
+    AppLifecycle, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, HoveredFile,
+    HoveredFileCancelled, Ime, RawMouseMotion, RequestRedraw, Window,
+    WindowBackendScaleFactorChanged, WindowCloseRequested, WindowDestroyed,
+    WindowEvent as BevyWindowEvent, WindowFocused, WindowMoved, WindowOccluded, WindowPosition,
+    WindowPresentation, WindowReady, WindowResized, WindowScaleFactorChanged, WindowSurfaceLost,
+    WindowSurfaceNeedsReconfigure, WindowSurfaceRestored, WindowThemeChanged,
 };
 #[cfg(target_os = "android")]
-use bevy_window::{PrimaryWindow, RawHandleWrapper};
+use bevy_window::{CursorGrabChanged, CursorGrabMode, PrimaryWindow, RawHandleWrapper};
 
+#[cfg(target_os = "android")]
+use crate::winit_windows::attempt_grab;
 use crate::{
     accessibility::AccessKitAdapters,
     converters, create_windows,
     system::{create_monitors, CachedWindow},
-    AppSendEvent, CreateMonitorParams, CreateWindowParams, EventLoopProxyWrapper,
-    RawWinitWindowEvent, UpdateMode, WinitSettings, WinitWindows,
+    winit_windows::{confined_cursor_position, PendingScaleFactor},
+    AppSendEvent, CreateMonitorParams, CreateWindowParams, EventLoopProxyWrapper, InteractionState,
+    RawWinitWindowEvent, ScaleFactorHysteresis, UpdateMode, WinitSettings, WinitWindows,
 };
 
 /// Persistent state that is used to run the [`App`] according to the current
@@ -77,6 +83,9 @@ struct WinitAppRunnerState<T: Event> {
     wait_elapsed: bool,
     /// Number of "forced" updates to trigger on application start
     startup_forced_updates: u32,
+    /// Is `true` while device events are received regardless of focus, which hit-test masks
+    /// need to follow the cursor while it passes through their windows.
+    listening_to_unfocused_device_events: bool,
 
     /// Current app lifecycle state.
     lifecycle: AppLifecycle,
@@ -92,10 +101,13 @@ struct WinitAppRunnerState<T: Event> {
         EventWriter<'static, WindowResized>,
         EventWriter<'static, WindowBackendScaleFactorChanged>,
         EventWriter<'static, WindowScaleFactorChanged>,
-        NonSend<'static, WinitWindows>,
+        NonSendMut<'static, WinitWindows>,
         Query<'static, 'static, (&'static mut Window, &'static mut CachedWindow)>,
         NonSendMut<'static, AccessKitAdapters>,
     )>,
+    /// Finds the window receiving raw mouse motion, which is cached as mouse motion is reported
+    /// at a high rate.
+    raw_mouse_motion_windows: QueryState<(Entity, &'static Window)>,
 }
 
 impl<T: Event> WinitAppRunnerState<T> {
@@ -107,10 +119,11 @@ impl<T: Event> WinitAppRunnerState<T> {
             EventWriter<WindowResized>,
             EventWriter<WindowBackendScaleFactorChanged>,
             EventWriter<WindowScaleFactorChanged>,
-            NonSend<WinitWindows>,
+            NonSendMut<WinitWindows>,
             Query<(&mut Window, &mut CachedWindow)>,
             NonSendMut<AccessKitAdapters>,
         )> = SystemState::new(app.world_mut());
+        let raw_mouse_motion_windows = app.world_mut().query();
 
         Self {
             app,
@@ -126,10 +139,12 @@ impl<T: Event> WinitAppRunnerState<T> {
             wait_elapsed: false,
             // 3 seems to be enough, 5 is a safe margin
             startup_forced_updates: 5,
+            listening_to_unfocused_device_events: false,
             bevy_window_events: Vec::new(),
             raw_winit_events: Vec::new(),
             _marker: PhantomData,
             event_writer_system_state,
+            raw_mouse_motion_windows,
         }
     }
 
@@ -160,6 +175,9 @@ pub enum CustomCursorCacheKey {
         flip_x: bool,
         flip_y: bool,
         rect: Option<URect>,
+        /// The bits of the scale factor the image was scaled by, see
+        /// [`CustomCursorImage::scale_with_window`](crate::cursor::CustomCursorImage::scale_with_window).
+        scale_factor: Option<u32>,
     },
     #[cfg(all(target_family = "wasm", target_os = "unknown"))]
     /// A custom cursor with a URL.
@@ -211,17 +229,7 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             self.redraw_requested = true;
         }
 
-        self.wait_elapsed = match cause {
-            StartCause::WaitCancelled {
-                requested_resume: Some(resume),
-                ..
-            } => {
-                // If the resume time is not after now, it means that at least the wait timeout
-                // has elapsed.
-                resume <= Instant::now()
-            }
-            _ => true,
-        };
+        self.wait_elapsed = wait_elapsed(cause, Instant::now());
     }
 
     fn resumed(&mut self, _event_loop: &ActiveEventLoop) {
@@ -243,13 +251,20 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
         window_id: WindowId,
         event: WindowEvent,
     ) {
+        let window_event_received = self.window_event_received;
         self.window_event_received = true;
 
+        let scale_factor_hysteresis = self
+            .world()
+            .get_resource::<ScaleFactorHysteresis>()
+            .copied()
+            .unwrap_or_default();
+
         let (
             mut window_resized,
             mut window_backend_scale_factor_changed,
             mut window_scale_factor_changed,
-            winit_windows,
+            mut winit_windows,
             mut windows,
             mut access_kit_adapters,
         ) = self.event_writer_system_state.get_mut(self.app.world_mut());
@@ -259,7 +274,7 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             return;
         };
 
-        let Ok((mut win, _)) = windows.get_mut(window) else {
+        let Ok((mut win, mut cache)) = windows.get_mut(window) else {
             warn!("Window {window:?} is missing `Window` component, skipping event {event:?}");
             return;
         };
@@ -278,22 +293,87 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             }
         }
 
+        if matches!(
+            event,
+            WindowEvent::KeyboardInput { .. }
+                | WindowEvent::CursorMoved { .. }
+                | WindowEvent::MouseInput { .. }
+                | WindowEvent::MouseWheel { .. }
+                | WindowEvent::Touch(_)
+                | WindowEvent::PinchGesture { .. }
+                | WindowEvent::RotationGesture { .. }
+                | WindowEvent::DoubleTapGesture { .. }
+                | WindowEvent::PanGesture { .. }
+                | WindowEvent::Ime(_)
+        ) {
+            winit_windows.record_input(window, Instant::now());
+        }
+
         match event {
             WindowEvent::Resized(size) => {
-                react_to_resize(window, &mut win, size, &mut window_resized);
-            }
-            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
-                react_to_scale_factor_change(
+                if let Some(pending) = winit_windows.pending_scale_factors.get_mut(&window) {
+                    // The platform resized the window for the pending scale factor, so the size is
+                    // applied together with it.
+                    pending.physical_size = Some(size);
+                } else if !winit_windows.hold_resize(window, size) {
+                    react_to_resize(window, &mut win, size, &mut window_resized);
+                }
+                winit_windows.record_interaction(
                     window,
-                    &mut win,
-                    scale_factor,
-                    &mut window_backend_scale_factor_changed,
-                    &mut window_scale_factor_changed,
+                    InteractionState::Resizing,
+                    Instant::now(),
                 );
+                if win.resize_redraw_delay.is_some() {
+                    // Let the deferred redraw drive the update once the resize has settled.
+                    winit_windows.record_resize(window, Instant::now());
+                    self.window_event_received = window_event_received;
+                }
+                let physical_size = UVec2::new(size.width, size.height);
+                let surface_size = win.surface_size();
+                // A held size is reported once the scale factor or batch it waits for is applied.
+                if !winit_windows.pending_scale_factors.contains_key(&window)
+                    && !winit_windows.held_resizes.contains_key(&window)
+                    && winit_windows.update_surface_size(window, physical_size, surface_size)
+                {
+                    self.bevy_window_events.send(WindowSurfaceNeedsReconfigure {
+                        window,
+                        physical_size,
+                        surface_size,
+                    });
+                }
+                if winit_windows.mark_ready(window) {
+                    self.bevy_window_events.send(WindowReady {
+                        window,
+                        physical_size,
+                    });
+                }
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                if !scale_factor_hysteresis.enabled {
+                    react_to_scale_factor_change(
+                        window,
+                        &mut win,
+                        scale_factor,
+                        &mut window_backend_scale_factor_changed,
+                        &mut window_scale_factor_changed,
+                    );
+                } else if relative_eq!(scale_factor as f32, win.resolution.base_scale_factor()) {
+                    // The window moved back before the change settled.
+                    winit_windows.pending_scale_factors.remove(&window);
+                } else {
+                    let mut pending = PendingScaleFactor::new(scale_factor);
+                    pending.physical_size = winit_windows
+                        .pending_scale_factors
+                        .get(&window)
+                        .and_then(|pending| pending.physical_size);
+                    winit_windows.pending_scale_factors.insert(window, pending);
+                }
+            }
+            WindowEvent::CloseRequested => {
+                winit_windows.pending_closes.insert(window);
+                self.bevy_window_events
+                    .send(WindowCloseRequested { window });
             }
-            WindowEvent::CloseRequested => self
-                .bevy_window_events
-                .send(WindowCloseRequested { window }),
             WindowEvent::KeyboardInput {
                 ref event,
                 // On some platforms, winit sends "synthetic" key press events when the window
@@ -306,7 +386,20 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
                     .send(converters::convert_keyboard_input(event, window));
             }
             WindowEvent::CursorMoved { position, .. } => {
-                let physical_position = DVec2::new(position.x, position.y);
+                let mut physical_position = DVec2::new(position.x, position.y);
+                let scale_factor = win.resolution.scale_factor();
+                if let Some(confined) = confined_cursor_position(
+                    win.cursor_options.grab_mode,
+                    win.cursor_options.confine_region,
+                    (physical_position / scale_factor as f64).as_vec2(),
+                ) {
+                    // The cursor left the confine region, so it is moved back to its edge.
+                    if let Some(winit_window) = winit_windows.get_window(window) {
+                        let _ = winit_window
+                            .set_cursor_position(LogicalPosition::new(confined.x, confined.y));
+                    }
+                    physical_position = confined.as_dvec2() * scale_factor as f64;
+                }
 
                 let last_position = win.physical_cursor_position();
                 let delta = last_position.map(|last_pos| {
@@ -315,6 +408,7 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
 
                 win.set_physical_cursor_position(Some(physical_position));
                 let position = (physical_position / win.resolution.scale_factor() as f64).as_vec2();
+                winit_windows.cursor_positions.insert(window, position);
                 self.bevy_window_events.send(CursorMoved {
                     window,
                     position,
@@ -326,6 +420,7 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             }
             WindowEvent::CursorLeft { .. } => {
                 win.set_physical_cursor_position(None);
+                winit_windows.cursor_positions.remove(&window);
                 self.bevy_window_events.send(CursorLeft { window });
             }
             WindowEvent::MouseInput { state, button, .. } => {
@@ -335,20 +430,38 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
                     window,
                 });
             }
-            WindowEvent::PinchGesture { delta, .. } => {
+            WindowEvent::PinchGesture { delta, phase, .. } => {
                 self.bevy_window_events.send(PinchGesture(delta as f32));
+                self.bevy_window_events.send(GestureInput {
+                    window,
+                    gesture: Gesture::Pinch(delta as f32),
+                    phase: converters::convert_touch_phase(phase),
+                });
             }
-            WindowEvent::RotationGesture { delta, .. } => {
+            WindowEvent::RotationGesture { delta, phase, .. } => {
                 self.bevy_window_events.send(RotationGesture(delta));
+                self.bevy_window_events.send(GestureInput {
+                    window,
+                    gesture: Gesture::Rotation(delta),
+                    phase: converters::convert_touch_phase(phase),
+                });
             }
             WindowEvent::DoubleTapGesture { .. } => {
                 self.bevy_window_events.send(DoubleTapGesture);
+                self.bevy_window_events.send(GestureInput {
+                    window,
+                    gesture: Gesture::DoubleTap,
+                    phase: TouchPhase::Ended,
+                });
             }
-            WindowEvent::PanGesture { delta, .. } => {
-                self.bevy_window_events.send(PanGesture(Vec2 {
-                    x: delta.x,
-                    y: delta.y,
-                }));
+            WindowEvent::PanGesture { delta, phase, .. } => {
+                let delta = Vec2::new(delta.x, delta.y);
+                self.bevy_window_events.send(PanGesture(delta));
+                self.bevy_window_events.send(GestureInput {
+                    window,
+                    gesture: Gesture::Pan(delta),
+                    phase: converters::convert_touch_phase(phase),
+                });
             }
             WindowEvent::MouseWheel { delta, .. } => match delta {
                 event::MouseScrollDelta::LineDelta(x, y) => {
@@ -377,10 +490,14 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             }
             WindowEvent::Focused(focused) => {
                 win.focused = focused;
+                if focused {
+                    winit_windows.raise_in_z_order(window);
+                }
                 self.bevy_window_events
                     .send(WindowFocused { window, focused });
             }
             WindowEvent::Occluded(occluded) => {
+                win.occluded = occluded;
                 self.bevy_window_events
                     .send(WindowOccluded { window, occluded });
             }
@@ -389,16 +506,31 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
                     .send(FileDragAndDrop::DroppedFile { window, path_buf });
             }
             WindowEvent::HoveredFile(path_buf) => {
+                self.bevy_window_events.send(HoveredFile {
+                    window,
+                    path: path_buf.clone(),
+                    position: win.cursor_position(),
+                });
                 self.bevy_window_events
                     .send(FileDragAndDrop::HoveredFile { window, path_buf });
             }
             WindowEvent::HoveredFileCancelled => {
+                self.bevy_window_events
+                    .send(HoveredFileCancelled { window });
                 self.bevy_window_events
                     .send(FileDragAndDrop::HoveredFileCanceled { window });
             }
             WindowEvent::Moved(position) => {
                 let position = ivec2(position.x, position.y);
-                win.position.set(position);
+                winit_windows.record_interaction(window, InteractionState::Moving, Instant::now());
+                // Anchored windows stay anchored so they can be docked again when the
+                // monitors change.
+                if !matches!(win.position, WindowPosition::AnchoredToMonitor { .. }) {
+                    win.position.set(position);
+                    // The window is already where the user moved it, so it isn't moved back
+                    // onto a monitor by `Window::keep_on_screen`.
+                    cache.window.position = win.position.clone();
+                }
                 self.bevy_window_events
                     .send(WindowMoved { window, position });
             }
@@ -430,7 +562,25 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
                 self.bevy_window_events.send(WindowDestroyed { window });
             }
             WindowEvent::RedrawRequested => {
-                self.ran_update_since_last_redraw = false;
+                // Redraws of a window in a burst of resizes, e.g. by the OS while the frame is
+                // dragged, are left to the deferred redraw once the resize has settled.
+                if winit_windows.is_resizing(window) {
+                    self.window_event_received = window_event_received;
+                } else {
+                    self.ran_update_since_last_redraw = false;
+                }
+
+                // Some platforms don't send a resize event for the initial size, so it is
+                // considered final once the window is drawn.
+                if let Some(winit_window) = winit_windows.get_window(window) {
+                    let size = winit_window.inner_size();
+                    if winit_windows.mark_ready(window) {
+                        self.bevy_window_events.send(WindowReady {
+                            window,
+                            physical_size: UVec2::new(size.width, size.height),
+                        });
+                    }
+                }
             }
             _ => {}
         }
@@ -454,6 +604,20 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
         if let DeviceEvent::MouseMotion { delta: (x, y) } = event {
             let delta = Vec2::new(x as f32, y as f32);
             self.bevy_window_events.send(MouseMotion { delta });
+
+            if let Some((window, _)) = self
+                .raw_mouse_motion_windows
+                .iter(self.app.world())
+                .find(|(_, window)| window.focused && window.raw_mouse_motion)
+            {
+                self.bevy_window_events
+                    .send(RawMouseMotion { window, delta });
+            }
+
+            let mut winit_windows = self.world_mut().non_send_resource_mut::<WinitWindows>();
+            if !winit_windows.hittest_masks.is_empty() {
+                winit_windows.accumulate_hittest_motion(delta);
+            }
         }
     }
 
@@ -497,6 +661,12 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             should_update = true;
             self.ran_update_since_last_redraw = false;
 
+            let mut windows = self.world_mut().query_filtered::<Entity, With<Window>>();
+            let windows: Vec<Entity> = windows.iter(self.world()).collect();
+            for window in windows {
+                self.bevy_window_events.send(WindowSurfaceLost { window });
+            }
+
             #[cfg(target_os = "android")]
             {
                 // Remove the `RawHandleWrapper` from the primary window.
@@ -531,15 +701,21 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
                         SystemState::<CreateWindowParams>::from_world(self.world_mut());
 
                     let (
-                        ..,
+                        _,
+                        _,
+                        _,
+                        mut cursor_grab_changed,
                         mut winit_windows,
                         mut adapters,
                         mut handlers,
                         accessibility_requested,
                         monitors,
+                        attributes_hook,
+                        _,
+                        presentation,
                     ) = create_window.get_mut(self.world_mut());
 
-                    let winit_window = winit_windows.create_window(
+                    let winit_window = winit_windows.recreate_window(
                         event_loop,
                         entity,
                         &window,
@@ -547,15 +723,56 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
                         &mut handlers,
                         &accessibility_requested,
                         &monitors,
+                        attributes_hook.as_deref(),
                     );
 
+                    if let Some(presentation) = &presentation {
+                        let winit_window = winit_window.clone();
+                        presentation.set_pre_present_hook(entity, move || {
+                            winit_window.pre_present_notify()
+                        });
+                    }
+
+                    // The grab is lost with the old window, so it is applied again like on
+                    // creation, and retried once the window gains focus if that fails.
+                    let grab_mode = window.cursor_options.grab_mode;
+                    let confine_region = window.cursor_options.confine_region;
+                    let grab_result = (grab_mode != CursorGrabMode::None)
+                        .then(|| attempt_grab(winit_window, grab_mode, confine_region));
+
                     let wrapper = RawHandleWrapper::new(winit_window).unwrap();
 
+                    match grab_result {
+                        Some(Ok(effective_mode)) => {
+                            cursor_grab_changed.send(CursorGrabChanged {
+                                window: entity,
+                                grab_mode: effective_mode,
+                            });
+                        }
+                        Some(Err(_)) => {
+                            winit_windows
+                                .pending_grabs
+                                .insert(entity, (grab_mode, confine_region));
+                        }
+                        None => {}
+                    }
+
                     self.world_mut().entity_mut(entity).insert(wrapper);
                 }
             }
         }
 
+        if self.lifecycle == AppLifecycle::Running
+            && self.previous_lifecycle == AppLifecycle::Suspended
+        {
+            let mut windows = self.world_mut().query_filtered::<Entity, With<Window>>();
+            let windows: Vec<Entity> = windows.iter(self.world()).collect();
+            for window in windows {
+                self.bevy_window_events
+                    .send(WindowSurfaceRestored { window });
+            }
+        }
+
         // Notifies a lifecycle change
         if self.lifecycle != self.previous_lifecycle {
             self.previous_lifecycle = self.lifecycle;
@@ -577,7 +794,12 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             // If no windows are visible, redraw calls will never succeed, which results in no app update calls being performed.
             // This is a temporary solution, full solution is mentioned here: https://github.com/bevyengine/bevy/issues/1343#issuecomment-770091684
             if !self.ran_update_since_last_redraw || all_invisible {
+                self.update_hittest_masks(event_loop);
                 self.run_app_update();
+                let presentation = self.world().get_resource::<WindowPresentation>().cloned();
+                self.world_mut()
+                    .non_send_resource_mut::<WinitWindows>()
+                    .show_windows_after_first_frame(presentation.as_ref());
                 #[cfg(feature = "custom_cursor")]
                 self.update_cursors(event_loop);
                 #[cfg(not(feature = "custom_cursor"))]
@@ -647,12 +869,36 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
             }
         }
 
-        if self.redraw_requested && self.lifecycle != AppLifecycle::Suspended {
-            let winit_windows = self.world().non_send_resource::<WinitWindows>();
-            for window in winit_windows.windows.values() {
-                window.request_redraw();
+        if self.lifecycle != AppLifecycle::Suspended {
+            let redraw_requested = self.redraw_requested;
+            let mut redraw_state =
+                SystemState::<(NonSendMut<WinitWindows>, Query<&Window>)>::from_world(
+                    self.world_mut(),
+                );
+            let (mut winit_windows, windows) = redraw_state.get_mut(self.world_mut());
+            // Windows whose resizes have settled are redrawn even if the app didn't request it,
+            // so that their final size is rendered.
+            let next_redraw =
+                winit_windows.request_redraws(Instant::now(), redraw_requested, |entity| {
+                    windows.get(entity).map_or((None, None), |window| {
+                        (window.redraw_interval, window.resize_redraw_delay)
+                    })
+                });
+            self.redraw_requested = redraw_requested && next_redraw.is_some();
+
+            // Wake up when the first throttled or resized window is due, and request its redraw
+            // then.
+            if let Some(next_redraw) = next_redraw {
+                match event_loop.control_flow() {
+                    ControlFlow::Wait => {
+                        event_loop.set_control_flow(ControlFlow::WaitUntil(next_redraw));
+                    }
+                    ControlFlow::WaitUntil(next) if next_redraw < next => {
+                        event_loop.set_control_flow(ControlFlow::WaitUntil(next_redraw));
+                    }
+                    _ => {}
+                }
             }
-            self.redraw_requested = false;
         }
 
         if let Some(app_exit) = self.app.should_exit() {
@@ -731,12 +977,21 @@ impl<T: Event> WinitAppRunnerState<T> {
                 BevyWindowEvent::CursorLeft(e) => {
                     world.send_event(e);
                 }
+                BevyWindowEvent::RawMouseMotion(e) => {
+                    world.send_event(e);
+                }
                 BevyWindowEvent::CursorMoved(e) => {
                     world.send_event(e);
                 }
                 BevyWindowEvent::FileDragAndDrop(e) => {
                     world.send_event(e);
                 }
+                BevyWindowEvent::HoveredFile(e) => {
+                    world.send_event(e);
+                }
+                BevyWindowEvent::HoveredFileCancelled(e) => {
+                    world.send_event(e);
+                }
                 BevyWindowEvent::Ime(e) => {
                     world.send_event(e);
                 }
@@ -770,6 +1025,18 @@ impl<T: Event> WinitAppRunnerState<T> {
                 BevyWindowEvent::WindowScaleFactorChanged(e) => {
                     world.send_event(e);
                 }
+                BevyWindowEvent::WindowSurfaceNeedsReconfigure(e) => {
+                    world.send_event(e);
+                }
+                BevyWindowEvent::WindowSurfaceLost(e) => {
+                    world.send_event(e);
+                }
+                BevyWindowEvent::WindowSurfaceRestored(e) => {
+                    world.send_event(e);
+                }
+                BevyWindowEvent::WindowReady(e) => {
+                    world.send_event(e);
+                }
                 BevyWindowEvent::WindowThemeChanged(e) => {
                     world.send_event(e);
                 }
@@ -794,6 +1061,9 @@ impl<T: Event> WinitAppRunnerState<T> {
                 BevyWindowEvent::PanGesture(e) => {
                     world.send_event(e);
                 }
+                BevyWindowEvent::GestureInput(e) => {
+                    world.send_event(e);
+                }
                 BevyWindowEvent::TouchInput(e) => {
                     world.send_event(e);
                 }
@@ -813,6 +1083,34 @@ impl<T: Event> WinitAppRunnerState<T> {
         }
     }
 
+    fn update_hittest_masks(&mut self, event_loop: &ActiveEventLoop) {
+        let has_masks = !self
+            .world()
+            .non_send_resource::<WinitWindows>()
+            .hittest_masks
+            .is_empty();
+        // While the cursor passes through a masked window, it is followed by raw mouse motion,
+        // which most platforms only report to the focused app by default.
+        if has_masks != self.listening_to_unfocused_device_events {
+            event_loop.listen_device_events(if has_masks {
+                DeviceEvents::Always
+            } else {
+                DeviceEvents::WhenFocused
+            });
+            self.listening_to_unfocused_device_events = has_masks;
+        }
+        if !has_masks {
+            return;
+        }
+
+        let mut hittest_state =
+            SystemState::<(NonSendMut<WinitWindows>, Query<&Window>)>::from_world(self.world_mut());
+        let (mut winit_windows, windows) = hittest_state.get_mut(self.world_mut());
+        winit_windows.update_hittest_masks(|entity| {
+            windows.get(entity).ok().and_then(Window::cursor_position)
+        });
+    }
+
     fn update_cursors(&mut self, #[cfg(feature = "custom_cursor")] event_loop: &ActiveEventLoop) {
         #[cfg(feature = "custom_cursor")]
         let mut windows_state: SystemState<(
@@ -926,6 +1224,12 @@ pub(crate) fn react_to_scale_factor_change(
     window_backend_scale_factor_changed: &mut EventWriter<WindowBackendScaleFactorChanged>,
     window_scale_factor_changed: &mut EventWriter<WindowScaleFactorChanged>,
 ) {
+    // Capture the prior factor before updating the resolution, otherwise the comparison below
+    // would always see the new value. The resolution is updated before any event is sent so
+    // readers observe the new scale factor.
+    let prior_factor = window.resolution.scale_factor();
+    let scale_factor_override = window.resolution.scale_factor_override();
+
     window.resolution.set_scale_factor(scale_factor as f32);
 
     window_backend_scale_factor_changed.send(WindowBackendScaleFactorChanged {
@@ -933,9 +1237,6 @@ pub(crate) fn react_to_scale_factor_change(
         scale_factor,
     });
 
-    let prior_factor = window.resolution.scale_factor();
-    let scale_factor_override = window.resolution.scale_factor_override();
-
     if scale_factor_override.is_none() && !relative_eq!(scale_factor as f32, prior_factor) {
         window_scale_factor_changed.send(WindowScaleFactorChanged {
             window: window_entity,
@@ -943,3 +1244,51 @@ pub(crate) fn react_to_scale_factor_change(
         });
     }
 }
+
+/// Returns whether the wait timeout of the event loop has elapsed when it wakes up at `now`.
+///
+/// A wait without a timeout never elapses, so events that wake it up only trigger an update if
+/// the update mode reacts to them, and resizes deferred by [`Window::resize_redraw_delay`] don't.
+fn wait_elapsed(cause: StartCause, now: Instant) -> bool {
+    match cause {
+        StartCause::WaitCancelled {
+            requested_resume, ..
+        } => {
+            // If the resume time is not after now, it means that at least the wait timeout has
+            // elapsed.
+            requested_resume.is_some_and(|resume| resume <= now)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+
+    #[test]
+    fn only_timed_out_waits_elapse() {
+        let start = Instant::now();
+        let now = start + Duration::from_millis(10);
+        let cancelled = |requested_resume| StartCause::WaitCancelled {
+            start,
+            requested_resume,
+        };
+
+        assert!(!wait_elapsed(cancelled(None), now));
+        assert!(!wait_elapsed(
+            cancelled(Some(now + Duration::from_millis(1))),
+            now
+        ));
+        assert!(wait_elapsed(cancelled(Some(now)), now));
+        assert!(wait_elapsed(
+            StartCause::ResumeTimeReached {
+                start,
+                requested_resume: now,
+            },
+            now
+        ));
+        assert!(wait_elapsed(StartCause::Poll, now));
+    }
+}
@@ -1,26 +1,39 @@
 use bevy_ecs::{
-    entity::Entity,
+    entity::{hash_map::EntityHashMap, hash_set::EntityHashSet, Entity},
     event::EventWriter,
-    prelude::{Changed, Component},
+    prelude::{Added, Changed, Component},
     query::QueryFilter,
     removal_detection::RemovedComponents,
-    system::{Local, NonSendMut, Query, SystemParamItem},
+    system::{Commands, Local, NonSend, NonSendMut, Query, SystemParamItem},
+    world::EntityWorldMut,
 };
 use bevy_input::keyboard::KeyboardFocusLost;
 use bevy_window::{
-    ClosingWindow, Monitor, PrimaryMonitor, RawHandleWrapper, VideoMode, Window, WindowClosed,
-    WindowClosing, WindowCreated, WindowFocused, WindowMode, WindowResized, WindowWrapper,
+    ClosingWindow, CursorGrabChanged, CursorGrabMode, Monitor, MonitorScaleFactorChanged,
+    MonitorSelection, MonitorVideoModesChanged, PrimaryMonitor, PrimaryMonitorChanged,
+    PrimaryWindow, RawHandleWrapper, TransparentWindow, VideoMode, Window, WindowActivationChanged,
+    WindowBackendScaleFactorChanged, WindowChromeColor, WindowClosed, WindowClosing, WindowCreated,
+    WindowFocused, WindowLevel, WindowMode, WindowMoved, WindowMovedToMonitor, WindowPosition,
+    WindowPresentation, WindowResized, WindowScaleFactorChanged, WindowSurfaceNeedsReconfigure,
+    WindowWrapper,
 };
 use tracing::{error, info, warn};
 
 use winit::{
-    dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize},
+    dpi::{LogicalPosition, PhysicalPosition, PhysicalSize},
     event_loop::ActiveEventLoop,
+    monitor::VideoModeHandle,
 };
 
 use bevy_app::AppExit;
-use bevy_ecs::{prelude::EventReader, query::With, system::Res};
-use bevy_math::{IVec2, UVec2};
+use bevy_ecs::{
+    prelude::EventReader,
+    query::With,
+    system::{Res, ResMut},
+};
+use bevy_math::{IVec2, UVec2, Vec2};
+use bevy_platform_support::time::Instant;
+use core::time::Duration;
 #[cfg(target_os = "ios")]
 use winit::platform::ios::WindowExtIOS;
 #[cfg(target_arch = "wasm32")]
@@ -32,11 +45,36 @@ use crate::{
         convert_window_theme, convert_winit_theme,
     },
     get_best_videomode, get_fitting_videomode, select_monitor,
-    state::react_to_resize,
-    winit_monitors::WinitMonitors,
-    CreateMonitorParams, CreateWindowParams, WinitWindows,
+    state::{react_to_resize, react_to_scale_factor_change},
+    winit_monitors::{monitor_identity, monitor_info, physical_size_mm, WinitMonitors},
+    winit_windows::{
+        activation_reason, actual_window_mode, aspect_corrected_size, attempt_grab,
+        exclusive_or_borderless, fullscreen_monitor_fallback, majority_monitor, set_backdrop,
+        set_chrome_colors, set_decorations_keeping_inner_size, set_modal_parent_enabled,
+        window_current_monitor,
+    },
+    CreateMonitorParams, CreateWindowParams, DisplayInfo, ModalWindow, MonitorIdentifier,
+    RememberedGeometry, ScaleFactorHysteresis, SplashWindow, WindowMemory, WinitWindows,
 };
 
+/// How often [`create_monitors`] checks known monitors for changed video modes.
+const VIDEO_MODE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Converts `winit` video modes to [`VideoMode`]s.
+fn convert_video_modes(video_modes: &[VideoModeHandle]) -> Vec<VideoMode> {
+    video_modes
+        .iter()
+        .map(|v| {
+            let size = v.size();
+            VideoMode {
+                physical_size: UVec2::new(size.width, size.height),
+                bit_depth: v.bit_depth(),
+                refresh_rate_millihertz: v.refresh_rate_millihertz(),
+            }
+        })
+        .collect()
+}
+
 /// Creates new windows on the [`winit`] backend for each entity with a newly-added
 /// [`Window`] component.
 ///
@@ -48,15 +86,22 @@ pub fn create_windows<F: QueryFilter + 'static>(
         mut commands,
         mut created_windows,
         mut window_created_events,
+        mut cursor_grab_changed_events,
         mut winit_windows,
         mut adapters,
         mut handlers,
         accessibility_requested,
         monitors,
+        attributes_hook,
+        default_icon,
+        presentation,
     ): SystemParamItem<CreateWindowParams<F>>,
 ) {
-    for (entity, mut window, handle_holder) in &mut created_windows {
-        if winit_windows.get_window(entity).is_some() {
+    let mut failed_grabs = Vec::new();
+    for (entity, mut window, handle_holder, has_external_handle) in &mut created_windows {
+        // Windows that already have a handle are owned outside of Bevy, see
+        // `RawHandleWrapper::from_raw`.
+        if winit_windows.get_window(entity).is_some() || has_external_handle {
             continue;
         }
 
@@ -70,8 +115,42 @@ pub fn create_windows<F: QueryFilter + 'static>(
             &mut handlers,
             &accessibility_requested,
             &monitors,
+            attributes_hook.as_deref(),
         );
 
+        if let Some(icon) = default_icon.as_ref().and_then(|icon| icon.icon()) {
+            winit_window.set_window_icon(Some(icon));
+        }
+
+        if let Some(presentation) = &presentation {
+            let winit_window = winit_window.clone();
+            presentation.set_pre_present_hook(entity, move || winit_window.pre_present_notify());
+        }
+
+        // Do not set the grab mode on window creation if it's none. It can fail on mobile, in
+        // which case it is retried once the window gains focus.
+        if window.cursor_options.grab_mode != CursorGrabMode::None {
+            match attempt_grab(
+                winit_window,
+                window.cursor_options.grab_mode,
+                window.cursor_options.confine_region,
+            ) {
+                Ok(grab_mode) => {
+                    cursor_grab_changed_events.send(CursorGrabChanged {
+                        window: entity,
+                        grab_mode,
+                    });
+                }
+                Err(_) => failed_grabs.push((
+                    entity,
+                    (
+                        window.cursor_options.grab_mode,
+                        window.cursor_options.confine_region,
+                    ),
+                )),
+            }
+        }
+
         if let Some(theme) = winit_window.theme() {
             window.window_theme = Some(convert_winit_theme(theme));
         }
@@ -80,9 +159,28 @@ pub fn create_windows<F: QueryFilter + 'static>(
             .resolution
             .set_scale_factor_and_apply_to_physical_size(winit_window.scale_factor() as f32);
 
-        commands.entity(entity).insert(CachedWindow {
-            window: window.clone(),
-        });
+        // The window falls back to another mode if its monitor isn't available.
+        #[cfg(target_os = "macos")]
+        let simple_fullscreen = window.simple_fullscreen;
+        #[cfg(not(target_os = "macos"))]
+        let simple_fullscreen = false;
+        if !simple_fullscreen {
+            if let Some(mode) =
+                actual_window_mode(window.mode.clone(), winit_window.fullscreen().as_ref())
+            {
+                window.mode = mode;
+            }
+        }
+
+        commands.entity(entity).insert((
+            CachedWindow {
+                window: window.clone(),
+            },
+            DisplayInfo::new(&window, winit_window.current_monitor().as_ref()),
+        ));
+        if window.transparent || window.shape_mask.is_some() {
+            commands.entity(entity).insert(TransparentWindow);
+        }
 
         if let Ok(handle_wrapper) = RawHandleWrapper::new(winit_window) {
             commands.entity(entity).insert(handle_wrapper.clone());
@@ -117,6 +215,83 @@ pub fn create_windows<F: QueryFilter + 'static>(
 
         window_created_events.send(WindowCreated { window: entity });
     }
+
+    winit_windows.pending_grabs.extend(failed_grabs);
+}
+
+/// Retries cursor grabs that failed, e.g. because the window wasn't ready yet, whenever their
+/// window gains focus.
+///
+/// Once a retried grab succeeds, the requested grab mode is written back to the [`Window`] and a
+/// [`CursorGrabChanged`] event is sent.
+pub(crate) fn retry_pending_grabs(
+    mut focus_events: EventReader<WindowFocused>,
+    mut windows: Query<(&mut Window, &mut CachedWindow)>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+    mut cursor_grab_changed: EventWriter<CursorGrabChanged>,
+) {
+    for event in focus_events.read() {
+        if !event.focused {
+            continue;
+        }
+        let Some(&(requested_mode, confine_region)) =
+            winit_windows.pending_grabs.get(&event.window)
+        else {
+            continue;
+        };
+        let (Some(winit_window), Ok((mut window, mut cache))) = (
+            winit_windows.get_window(event.window),
+            windows.get_mut(event.window),
+        ) else {
+            continue;
+        };
+        let Ok(grab_mode) = attempt_grab(winit_window, requested_mode, confine_region) else {
+            continue;
+        };
+
+        winit_windows.pending_grabs.remove(&event.window);
+        window.cursor_options.grab_mode = requested_mode;
+        window.cursor_options.confine_region = confine_region;
+        cache.window.cursor_options = window.cursor_options.clone();
+        cursor_grab_changed.send(CursorGrabChanged {
+            window: event.window,
+            grab_mode,
+        });
+    }
+}
+
+/// Sends [`WindowActivationChanged`] for windows whose focus changed this frame, with the
+/// reason inferred from whether they are minimized or occluded.
+pub(crate) fn detect_window_activation_changes(
+    mut focus_events: EventReader<WindowFocused>,
+    mut activation_events: EventWriter<WindowActivationChanged>,
+    windows: Query<&Window>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+) {
+    let focus_changes: EntityHashMap<bool> = focus_events
+        .read()
+        .map(|event| (event.window, event.focused))
+        .collect();
+
+    for (window, active) in focus_changes {
+        let previous = winit_windows.activation(window);
+        if previous.is_some_and(|(was_active, _)| was_active == active) {
+            continue;
+        }
+        let minimized = winit_windows
+            .get_window(window)
+            .and_then(|winit_window| winit_window.is_minimized())
+            == Some(true);
+        let occluded = windows.get(window).is_ok_and(|window| window.occluded);
+        let reason = activation_reason(active, previous, minimized, occluded);
+
+        winit_windows.activations.insert(window, (active, reason));
+        activation_events.send(WindowActivationChanged {
+            window,
+            active,
+            reason,
+        });
+    }
 }
 
 /// Check whether keyboard focus was lost. This is different from window
@@ -139,24 +314,506 @@ pub(crate) fn check_keyboard_focus_lost(
     }
 }
 
+/// Closes [`SplashWindow`]s once their main window has presented its first frame or is gone.
+pub(crate) fn close_splash_windows(
+    mut commands: Commands,
+    splash_windows: Query<(Entity, &SplashWindow)>,
+    windows: Query<(), With<Window>>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+) {
+    winit_windows.splash_windows.clear();
+    for (entity, splash) in &splash_windows {
+        if winit_windows.has_presented_first_frame(splash.main_window)
+            || windows.get(splash.main_window).is_err()
+        {
+            commands.entity(entity).despawn();
+        } else {
+            winit_windows
+                .splash_windows
+                .insert(entity, splash.main_window);
+        }
+    }
+}
+
+/// Closes [`MonitorIdentifier`] windows once they have expired.
+pub(crate) fn close_monitor_identifiers(
+    mut commands: Commands,
+    identifiers: Query<(Entity, &MonitorIdentifier)>,
+) {
+    let now = Instant::now();
+    for (entity, identifier) in &identifiers {
+        if identifier.close_at <= now {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Moves the cursor back to the center of focused windows in relative mouse mode whose cursor
+/// could only be confined, see [`WinitWindows::enable_relative_mouse`].
+pub(crate) fn recenter_relative_mouse_cursors(winit_windows: NonSend<WinitWindows>) {
+    for (entity, grab_mode) in &winit_windows.relative_mouse {
+        if *grab_mode != CursorGrabMode::Confined {
+            continue;
+        }
+        let Some(winit_window) = winit_windows.get_window(*entity) else {
+            continue;
+        };
+        if !winit_window.has_focus() {
+            continue;
+        }
+        let size = winit_window.inner_size();
+        let center = PhysicalPosition::new(size.width / 2, size.height / 2);
+        if let Err(err) = winit_window.set_cursor_position(center) {
+            bevy_log::debug!("could not recenter cursor: {}", err);
+        }
+    }
+}
+
+/// Blocks the parents of [`ModalWindow`]s while the modal window is open.
+pub(crate) fn update_modal_windows(
+    modal_windows: Query<(Entity, &ModalWindow)>,
+    mut focus_events: EventReader<WindowFocused>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+) {
+    // Closed modal windows already unblocked their parent when their window was removed, so
+    // this only handles removed components and changed parents.
+    let stale_modals: Vec<Entity> = winit_windows
+        .modal_parents
+        .iter()
+        .filter(|(modal, parent)| {
+            !modal_windows
+                .get(**modal)
+                .is_ok_and(|(_, modal_window)| modal_window.parent == **parent)
+        })
+        .map(|(modal, _)| *modal)
+        .collect();
+    for modal in stale_modals {
+        winit_windows.unblock_modal_parent(modal);
+    }
+
+    for (modal, modal_window) in &modal_windows {
+        if winit_windows.modal_parents.contains_key(&modal)
+            || winit_windows.get_window(modal).is_none()
+        {
+            continue;
+        }
+        if let Some(parent_window) = winit_windows.get_window(modal_window.parent) {
+            set_modal_parent_enabled(parent_window, false);
+            winit_windows
+                .modal_parents
+                .insert(modal, modal_window.parent);
+        }
+    }
+
+    for event in focus_events.read() {
+        if !event.focused {
+            continue;
+        }
+        let Some((modal, _)) = winit_windows
+            .modal_parents
+            .iter()
+            .find(|(_, parent)| **parent == event.window)
+        else {
+            continue;
+        };
+        if let Some(modal_window) = winit_windows.get_window(*modal) {
+            modal_window.focus_window();
+        }
+    }
+}
+
+/// Raises and lowers windows using [`WindowLevel::AlwaysOnTopWhenFocused`] as they gain and
+/// lose focus.
+pub(crate) fn update_focused_window_levels(
+    mut focus_events: EventReader<WindowFocused>,
+    windows: Query<&Window>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    for event in focus_events.read() {
+        let Ok(window) = windows.get(event.window) else {
+            continue;
+        };
+        if window.window_level != WindowLevel::AlwaysOnTopWhenFocused {
+            continue;
+        }
+        if let Some(winit_window) = winit_windows.get_window(event.window) {
+            winit_window.set_window_level(convert_window_level(window.window_level, event.focused));
+        }
+    }
+}
+
+/// Re-raises windows above their anchor when the anchor gains focus, see
+/// [`WinitWindows::set_window_above`].
+pub(crate) fn raise_anchored_windows(
+    mut focus_events: EventReader<WindowFocused>,
+    windows: Query<&Window>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    for event in focus_events.read() {
+        if !event.focused {
+            continue;
+        }
+        for (dependent, _) in winit_windows
+            .window_anchors
+            .iter()
+            .filter(|(_, anchor)| **anchor == event.window)
+        {
+            let (Ok(window), Some(winit_window)) = (
+                windows.get(*dependent),
+                winit_windows.get_window(*dependent),
+            ) else {
+                continue;
+            };
+            winit_window.set_window_level(convert_window_level(WindowLevel::AlwaysOnTop, false));
+            winit_window.set_window_level(convert_window_level(window.window_level, false));
+        }
+    }
+}
+
+/// Moves windows in [`WindowMode::BorderlessFullscreen`] on [`MonitorSelection::Primary`] to the
+/// new primary monitor when it changes, e.g. after the user reconfigures their displays.
+pub(crate) fn follow_primary_monitor(
+    new_primary: Query<Entity, Added<PrimaryMonitor>>,
+    windows: Query<(Entity, &Window)>,
+    monitors: Res<WinitMonitors>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    let Some(primary) = new_primary.iter().next() else {
+        return;
+    };
+    let primary_monitor = monitors.find_entity(primary);
+
+    for (entity, window) in &windows {
+        if window.mode != WindowMode::BorderlessFullscreen(MonitorSelection::Primary) {
+            continue;
+        }
+        #[cfg(target_os = "macos")]
+        if window.simple_fullscreen {
+            continue;
+        }
+        let Some(winit_window) = winit_windows.get_window(entity) else {
+            continue;
+        };
+
+        let fullscreen = Some(winit::window::Fullscreen::Borderless(select_monitor(
+            &monitors,
+            primary_monitor.clone(),
+            None,
+            &MonitorSelection::Primary,
+        )));
+        if winit_window.fullscreen() != fullscreen {
+            winit_window.set_fullscreen(fullscreen);
+        }
+    }
+}
+
+/// Docks windows with a [`WindowPosition::AnchoredToMonitor`] position again when the monitors
+/// change, e.g. after a resolution change, so they stay at the same edge.
+pub(crate) fn reapply_anchored_window_positions(
+    changed_monitors: Query<(), Changed<Monitor>>,
+    mut removed_monitors: RemovedComponents<Monitor>,
+    new_primary: Query<(), Added<PrimaryMonitor>>,
+    windows: Query<(Entity, &Window)>,
+    monitors: Res<WinitMonitors>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    let removed = removed_monitors.read().count() > 0;
+    if changed_monitors.is_empty() && new_primary.is_empty() && !removed {
+        return;
+    }
+
+    for (entity, window) in &windows {
+        if !matches!(window.position, WindowPosition::AnchoredToMonitor { .. }) {
+            continue;
+        }
+        let Some(winit_window) = winit_windows.get_window(entity) else {
+            continue;
+        };
+        let Some(position) = crate::winit_window_position(
+            &window.position,
+            &window.resolution,
+            window.keep_on_screen,
+            &monitors,
+            winit_window.primary_monitor(),
+            winit_window.current_monitor(),
+        ) else {
+            continue;
+        };
+        if winit_window.outer_position().ok() != Some(position) {
+            winit_window.set_outer_position(position);
+        }
+    }
+}
+
+/// Applies scale factor changes delayed by [`ScaleFactorHysteresis`] once their window has settled
+/// on a monitor with that scale factor.
+pub(crate) fn apply_settled_scale_factors(
+    mut winit_windows: NonSendMut<WinitWindows>,
+    monitors: Res<WinitMonitors>,
+    hysteresis: Res<ScaleFactorHysteresis>,
+    mut windows: Query<&mut Window>,
+    mut window_backend_scale_factor_changed: EventWriter<WindowBackendScaleFactorChanged>,
+    mut window_scale_factor_changed: EventWriter<WindowScaleFactorChanged>,
+    mut window_resized: EventWriter<WindowResized>,
+    mut surface_needs_reconfigure: EventWriter<WindowSurfaceNeedsReconfigure>,
+) {
+    if winit_windows.pending_scale_factors.is_empty() {
+        return;
+    }
+
+    let WinitWindows {
+        windows: wrappers,
+        entity_to_winit,
+        pending_scale_factors,
+        surface_sizes,
+        ..
+    } = &mut *winit_windows;
+    pending_scale_factors.retain(|&entity, pending| {
+        let Some(winit_window) = entity_to_winit.get(&entity).and_then(|id| wrappers.get(id))
+        else {
+            return false;
+        };
+        let majority_scale_factor =
+            majority_monitor(&monitors, winit_window).map(|monitor| monitor.scale_factor());
+        if hysteresis.enabled && !pending.settle(majority_scale_factor, hysteresis.settle_frames) {
+            // Keep updating in reactive modes until the change settles.
+            winit_window.request_redraw();
+            return true;
+        }
+        if let Ok(mut window) = windows.get_mut(entity) {
+            react_to_scale_factor_change(
+                entity,
+                &mut window,
+                pending.scale_factor,
+                &mut window_backend_scale_factor_changed,
+                &mut window_scale_factor_changed,
+            );
+            if let Some(physical_size) = pending.physical_size {
+                react_to_resize(entity, &mut window, physical_size, &mut window_resized);
+            }
+            let sizes = (window.physical_size(), window.surface_size());
+            if surface_sizes.insert(entity, sizes) != Some(sizes) {
+                surface_needs_reconfigure.send(WindowSurfaceNeedsReconfigure {
+                    window: entity,
+                    physical_size: sizes.0,
+                    surface_size: sizes.1,
+                });
+            }
+        }
+        false
+    });
+}
+
+/// Corrects user resizes of windows using [`Window::preserve_aspect_ratio`].
+pub(crate) fn preserve_window_aspect_ratios(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Query<&Window>,
+    winit_windows: NonSend<WinitWindows>,
+    mut previous_sizes: Local<EntityHashMap<Vec2>>,
+) {
+    for event in resize_events.read() {
+        let size = Vec2::new(event.width, event.height);
+        let Some(previous_size) = previous_sizes.insert(event.window, size) else {
+            continue;
+        };
+        let Ok(window) = windows.get(event.window) else {
+            continue;
+        };
+        let Some(aspect_ratio) = window.preserve_aspect_ratio else {
+            continue;
+        };
+        if window.mode != WindowMode::Windowed
+            || winit_windows
+                .get_window(event.window)
+                .is_none_or(|winit_window| winit_window.is_maximized())
+        {
+            continue;
+        }
+        if let Some(corrected_size) = aspect_corrected_size(previous_size, size, aspect_ratio) {
+            winit_windows.request_inner_size(event.window, corrected_size);
+        }
+    }
+    previous_sizes.retain(|entity, _| windows.contains(*entity));
+}
+
+/// Records the geometry of the primary window on its current monitor in [`WindowMemory`]
+/// whenever it is moved or resized.
+///
+/// Nothing is recorded in the frame the window lands on another monitor, so the geometry
+/// remembered for that monitor survives until it is restored.
+pub(crate) fn remember_window_geometry(
+    mut moved_events: EventReader<WindowMoved>,
+    mut resized_events: EventReader<WindowResized>,
+    memory: Option<ResMut<WindowMemory>>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    monitors: Res<WinitMonitors>,
+    winit_windows: NonSend<WinitWindows>,
+    mut last_monitor: Local<Option<Entity>>,
+) {
+    let changed: EntityHashSet = moved_events
+        .read()
+        .map(|event| event.window)
+        .chain(resized_events.read().map(|event| event.window))
+        .collect();
+    let (Some(mut memory), Ok((entity, window))) = (memory, primary_window.get_single()) else {
+        return;
+    };
+    if !changed.contains(&entity) || window.mode != WindowMode::Windowed {
+        return;
+    }
+    let Some(winit_window) = winit_windows.get_window(entity) else {
+        return;
+    };
+    let Some(monitor) = window_current_monitor(&monitors, winit_window) else {
+        return;
+    };
+    let monitor_entity = monitors.find_monitor_entity(&monitor);
+    if core::mem::replace(&mut *last_monitor, monitor_entity) != monitor_entity {
+        return;
+    }
+    if winit_window.is_maximized() || winit_window.is_minimized() == Some(true) {
+        return;
+    }
+    let (Some(identity), Ok(position)) = (
+        monitor_identity(&monitors, &monitor),
+        winit_window.outer_position(),
+    ) else {
+        return;
+    };
+
+    let monitor_position = monitor.position();
+    let size = winit_window.inner_size();
+    let geometry = RememberedGeometry {
+        offset: IVec2::new(
+            position.x - monitor_position.x,
+            position.y - monitor_position.y,
+        ),
+        size: UVec2::new(size.width, size.height),
+    };
+    if memory.get(&identity) != Some(geometry) {
+        memory.remember(identity, geometry);
+    }
+}
+
+/// Sends [`WindowMovedToMonitor`] when a window has moved onto a different monitor.
+///
+/// The monitor is only looked up once per frame for each window that moved.
+pub(crate) fn detect_window_monitor_changes(
+    mut created_events: EventReader<WindowCreated>,
+    mut moved_events: EventReader<WindowMoved>,
+    mut moved_to_monitor_events: EventWriter<WindowMovedToMonitor>,
+    windows: Query<(), With<Window>>,
+    monitors: Res<WinitMonitors>,
+    winit_windows: NonSend<WinitWindows>,
+    mut window_monitors: Local<EntityHashMap<Entity>>,
+) {
+    let current_monitor = |window: Entity| {
+        let monitor = winit_windows.get_window(window)?.current_monitor()?;
+        monitors.find_monitor_entity(&monitor)
+    };
+
+    for event in created_events.read() {
+        if let Some(monitor) = current_monitor(event.window) {
+            window_monitors.insert(event.window, monitor);
+        }
+    }
+
+    let moved: EntityHashSet = moved_events.read().map(|event| event.window).collect();
+    for window in moved {
+        let Some(monitor) = current_monitor(window) else {
+            continue;
+        };
+        if window_monitors.insert(window, monitor) != Some(monitor) {
+            moved_to_monitor_events.send(WindowMovedToMonitor { window, monitor });
+        }
+    }
+
+    window_monitors.retain(|entity, _| windows.contains(*entity));
+}
+
 /// Synchronize available monitors as reported by [`winit`] with [`Monitor`] entities in the world.
 pub fn create_monitors(
     event_loop: &ActiveEventLoop,
-    (mut commands, mut monitors): SystemParamItem<CreateMonitorParams>,
+    (mut commands, mut monitors, virtual_monitor, known_monitors): SystemParamItem<
+        CreateMonitorParams,
+    >,
 ) {
+    monitors.virtual_monitor = virtual_monitor.as_deref().copied();
     let primary_monitor = event_loop.primary_monitor();
     let mut seen_monitors = vec![false; monitors.monitors.len()];
 
+    // Re-enumerating video modes can be expensive, so known monitors are only checked for
+    // changed modes periodically.
+    let now = Instant::now();
+    let check_video_modes = monitors
+        .last_video_mode_check
+        .is_none_or(|last| now.duration_since(last) >= VIDEO_MODE_CHECK_INTERVAL);
+    if check_video_modes {
+        monitors.last_video_mode_check = Some(now);
+    }
+
     'outer: for monitor in event_loop.available_monitors() {
-        for (idx, (m, _)) in monitors.monitors.iter().enumerate() {
+        for (idx, (m, entity)) in monitors.monitors.iter().enumerate() {
             if &monitor == m {
                 seen_monitors[idx] = true;
+                let entity = *entity;
+                if check_video_modes {
+                    // Some backends cache the geometry in the handle, so keep the fresh handle
+                    // when the resolution, arrangement or scale factor changed. Other backends
+                    // query it live, so changes are detected against the `Monitor` component.
+                    let size = monitor.size();
+                    let position = IVec2::new(monitor.position().x, monitor.position().y);
+                    let scale_factor = monitor.scale_factor();
+                    let known = known_monitors.get(entity).ok();
+                    let scale_factor_changed =
+                        known.is_some_and(|known| known.scale_factor != scale_factor);
+                    let geometry_changed = known.is_some_and(|known| {
+                        known.physical_width != size.width
+                            || known.physical_height != size.height
+                            || known.physical_position != position
+                    }) || scale_factor_changed;
+                    if geometry_changed {
+                        commands
+                            .entity(entity)
+                            .queue(move |mut entity: EntityWorldMut| {
+                                if let Some(mut monitor) = entity.get_mut::<Monitor>() {
+                                    monitor.physical_width = size.width;
+                                    monitor.physical_height = size.height;
+                                    monitor.physical_position = position;
+                                    monitor.scale_factor = scale_factor;
+                                }
+                            });
+                        if scale_factor_changed {
+                            commands.send_event(MonitorScaleFactorChanged {
+                                monitor: entity,
+                                scale_factor,
+                            });
+                        }
+                        monitors.monitors[idx].0 = monitor.clone();
+                    }
+                    let video_modes: Vec<_> = monitor.video_modes().collect();
+                    if monitors.video_modes.get(&entity) != Some(&video_modes) {
+                        let converted = convert_video_modes(&video_modes);
+                        monitors.video_modes.insert(entity, video_modes);
+                        commands
+                            .entity(entity)
+                            .queue(move |mut entity: EntityWorldMut| {
+                                if let Some(mut monitor) = entity.get_mut::<Monitor>() {
+                                    monitor.video_modes = converted;
+                                }
+                            });
+                        commands.send_event(MonitorVideoModesChanged { monitor: entity });
+                    }
+                }
                 continue 'outer;
             }
         }
 
         let size = monitor.size();
         let position = monitor.position();
+        let video_modes: Vec<_> = monitor.video_modes().collect();
+        let physical_size = physical_size_mm(&monitor);
+        let info = monitor_info(&monitor);
 
         let entity = commands
             .spawn(Monitor {
@@ -166,40 +823,61 @@ pub fn create_monitors(
                 physical_position: IVec2::new(position.x, position.y),
                 refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
                 scale_factor: monitor.scale_factor(),
-                video_modes: monitor
-                    .video_modes()
-                    .map(|v| {
-                        let size = v.size();
-                        VideoMode {
-                            physical_size: UVec2::new(size.width, size.height),
-                            bit_depth: v.bit_depth(),
-                            refresh_rate_millihertz: v.refresh_rate_millihertz(),
-                        }
-                    })
-                    .collect(),
+                video_modes: convert_video_modes(&video_modes),
             })
             .id();
 
-        if primary_monitor.as_ref() == Some(&monitor) {
-            commands.entity(entity).insert(PrimaryMonitor);
-        }
-
         seen_monitors.push(true);
         monitors.monitors.push((monitor, entity));
+        monitors.video_modes.insert(entity, video_modes);
+        if let Some(physical_size) = physical_size {
+            monitors.physical_sizes.insert(entity, physical_size);
+        }
+        monitors.infos.insert(entity, info);
     }
 
+    let WinitMonitors {
+        monitors,
+        video_modes,
+        physical_sizes,
+        infos,
+        primary,
+        ..
+    } = &mut *monitors;
     let mut idx = 0;
-    monitors.monitors.retain(|(_m, entity)| {
+    monitors.retain(|(_m, entity)| {
         if seen_monitors[idx] {
             idx += 1;
             true
         } else {
             info!("Monitor removed {}", entity);
+            video_modes.remove(entity);
+            physical_sizes.remove(entity);
+            infos.remove(entity);
             commands.entity(*entity).despawn();
             idx += 1;
             false
         }
     });
+
+    // The primary monitor can also change without monitors being connected or disconnected,
+    // e.g. when the user reconfigures their displays.
+    let new_primary = primary_monitor.and_then(|primary_monitor| {
+        monitors
+            .iter()
+            .find(|(monitor, _)| *monitor == primary_monitor)
+            .map(|(_, entity)| *entity)
+    });
+    if new_primary != *primary {
+        if let Some(old_primary) = primary.take() {
+            commands.entity(old_primary).try_remove::<PrimaryMonitor>();
+        }
+        if let Some(new_primary) = new_primary {
+            commands.entity(new_primary).insert(PrimaryMonitor);
+        }
+        *primary = new_primary;
+        commands.send_event(PrimaryMonitorChanged { new_primary });
+    }
 }
 
 pub(crate) fn despawn_windows(
@@ -211,9 +889,12 @@ pub(crate) fn despawn_windows(
     mut winit_windows: NonSendMut<WinitWindows>,
     mut windows_to_drop: Local<Vec<WindowWrapper<winit::window::Window>>>,
     mut exit_events: EventReader<AppExit>,
+    presentation: Option<Res<WindowPresentation>>,
 ) {
     // Drop all the windows that are waiting to be closed
     windows_to_drop.clear();
+    // Windows replaced by `WinitWindows::recreate_window` are kept for one frame as well.
+    windows_to_drop.append(&mut winit_windows.recreated_windows);
     for window in closing.iter() {
         closing_events.send(WindowClosing { window });
     }
@@ -223,6 +904,9 @@ pub(crate) fn despawn_windows(
         // rather than having the component added
         // and removed in the same frame.
         if !window_entities.contains(window) {
+            if let Some(presentation) = &presentation {
+                presentation.remove_window(window);
+            }
             if let Some(window) = winit_windows.remove_window(window) {
                 // Keeping WindowWrapper that are dropped for one frame
                 // Otherwise the last `Arc` of the window could be in the rendering thread, and dropped there
@@ -244,6 +928,191 @@ pub(crate) fn despawn_windows(
     }
 }
 
+/// Closes windows whose pending close was confirmed with [`WinitWindows::confirm_close`].
+///
+/// Like windows whose close wasn't vetoed, they are marked as [`ClosingWindow`] first, so the
+/// renderer can release their surfaces, and despawned in the next update. This doesn't rely on
+/// [`close_when_requested`](bevy_window::close_when_requested), which may be disabled.
+pub(crate) fn close_confirmed_windows(
+    mut commands: Commands,
+    mut winit_windows: NonSendMut<WinitWindows>,
+    mut closing: Local<Vec<Entity>>,
+) {
+    for window in closing.drain(..) {
+        commands.entity(window).try_despawn();
+    }
+    for window in winit_windows.confirmed_closes.drain() {
+        commands.entity(window).try_insert(ClosingWindow);
+        closing.push(window);
+    }
+}
+
+/// Writes resize constraints changed through [`WinitWindows`] back to
+/// [`Window::resize_constraints`], e.g. after [`WinitWindows::set_min_inner_size`].
+///
+/// Changes to the component since the last update take precedence and are applied by
+/// `changed_windows` instead.
+pub(crate) fn sync_resize_constraints(
+    mut windows: Query<(Entity, &mut Window, &mut CachedWindow)>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    for (entity, mut window, mut cache) in &mut windows {
+        let Some(&constraints) = winit_windows.resize_constraints.get(&entity) else {
+            continue;
+        };
+        if constraints == cache.window.resize_constraints
+            || window.resize_constraints != cache.window.resize_constraints
+        {
+            continue;
+        }
+        window.resize_constraints = constraints;
+        cache.window.resize_constraints = constraints;
+    }
+}
+
+/// Writes changes applied with [`WinitWindows::update_window`] back to the [`Window`].
+///
+/// Like in `sync_resize_constraints`, changes to the component since the last update take
+/// precedence and are applied by `changed_windows` instead.
+pub(crate) fn sync_window_updates(
+    mut windows: Query<(&mut Window, &mut CachedWindow)>,
+    mut winit_windows: NonSendMut<WinitWindows>,
+) {
+    for (entity, update) in winit_windows.window_updates.drain() {
+        let Ok((mut window, mut cache)) = windows.get_mut(entity) else {
+            continue;
+        };
+        if let Some(title) = update.title {
+            if window.title == cache.window.title {
+                window.title.clone_from(&title);
+                cache.window.title = title;
+            }
+        }
+        if let Some(size) = update.inner_size {
+            if window.resolution == cache.window.resolution {
+                window.resolution.set(size.x, size.y);
+                cache.window.resolution.set(size.x, size.y);
+            }
+        }
+        if let Some(decorations) = update.decorations {
+            if window.decorations == cache.window.decorations {
+                window.decorations = decorations;
+                cache.window.decorations = decorations;
+            }
+        }
+        if let Some(window_level) = update.window_level {
+            if window.window_level == cache.window.window_level {
+                window.window_level = window_level;
+                cache.window.window_level = window_level;
+            }
+        }
+        if let Some(position) = update.position {
+            if window.position == cache.window.position {
+                window.position = WindowPosition::At(position);
+                cache.window.position = WindowPosition::At(position);
+            }
+        }
+        if let Some(visible) = update.visible {
+            if window.visible == cache.window.visible {
+                window.visible = visible;
+                cache.window.visible = visible;
+            }
+        }
+    }
+}
+
+/// Restores the gamma ramps changed with [`WinitMonitors::set_gamma`] when the app exits.
+pub(crate) fn restore_gamma_on_exit(
+    mut exit_events: EventReader<AppExit>,
+    mut monitors: ResMut<WinitMonitors>,
+) {
+    if exit_events.is_empty() {
+        return;
+    }
+    exit_events.clear();
+    monitors.restore_gamma_ramps();
+}
+
+/// Applies resizes held back by [`WinitWindows::update_window`] once the window didn't reach
+/// the requested size in time.
+pub(crate) fn apply_held_resizes(
+    mut winit_windows: NonSendMut<WinitWindows>,
+    mut windows: Query<&mut Window>,
+    mut window_resized: EventWriter<WindowResized>,
+    mut surface_needs_reconfigure: EventWriter<WindowSurfaceNeedsReconfigure>,
+) {
+    if winit_windows.held_resizes.is_empty() {
+        return;
+    }
+
+    let now = Instant::now();
+    let WinitWindows {
+        windows: wrappers,
+        entity_to_winit,
+        held_resizes,
+        surface_sizes,
+        ..
+    } = &mut *winit_windows;
+    held_resizes.retain(|&entity, held| {
+        let Some(winit_window) = entity_to_winit.get(&entity).and_then(|id| wrappers.get(id))
+        else {
+            return false;
+        };
+        if now < held.until {
+            // Keep updating in reactive modes until the hold times out.
+            winit_window.request_redraw();
+            return true;
+        }
+        if let (Some(physical_size), Ok(mut window)) = (held.physical_size, windows.get_mut(entity))
+        {
+            react_to_resize(entity, &mut window, physical_size, &mut window_resized);
+            let sizes = (window.physical_size(), window.surface_size());
+            if surface_sizes.insert(entity, sizes) != Some(sizes) {
+                surface_needs_reconfigure.send(WindowSurfaceNeedsReconfigure {
+                    window: entity,
+                    physical_size: sizes.0,
+                    surface_size: sizes.1,
+                });
+            }
+        }
+        false
+    });
+}
+
+/// Writes the fullscreen state windows actually have back to [`Window::mode`], e.g. after the
+/// user left fullscreen through the OS.
+///
+/// Entering or leaving fullscreen resizes the window, so only resized windows are checked, once
+/// the platform has finished the transition. Windows with a pending mode change from the app are
+/// skipped until it has been applied.
+pub(crate) fn sync_window_modes(
+    mut resized: EventReader<WindowResized>,
+    mut windows: Query<(&mut Window, &mut CachedWindow)>,
+    winit_windows: NonSend<WinitWindows>,
+) {
+    for entity in resized.read().map(|event| event.window) {
+        let Ok((mut window, mut cache)) = windows.get_mut(entity) else {
+            continue;
+        };
+        if window.mode != cache.window.mode {
+            continue;
+        }
+        #[cfg(target_os = "macos")]
+        if window.simple_fullscreen {
+            continue;
+        }
+        let Some(winit_window) = winit_windows.get_window(entity) else {
+            continue;
+        };
+        if let Some(mode) =
+            actual_window_mode(window.mode.clone(), winit_window.fullscreen().as_ref())
+        {
+            cache.window.mode = mode.clone();
+            window.mode = mode;
+        }
+    }
+}
+
 /// The cached state of the window so we can check which properties were changed from within the app.
 #[derive(Debug, Clone, Component)]
 pub struct CachedWindow {
@@ -260,59 +1129,108 @@ pub struct CachedWindow {
 /// - [`Window::focused`] cannot be manually changed to `false` after the window is created.
 pub(crate) fn changed_windows(
     mut changed_windows: Query<(Entity, &mut Window, &mut CachedWindow), Changed<Window>>,
-    winit_windows: NonSendMut<WinitWindows>,
+    mut winit_windows: NonSendMut<WinitWindows>,
     monitors: Res<WinitMonitors>,
     mut window_resized: EventWriter<WindowResized>,
+    mut cursor_grab_changed: EventWriter<CursorGrabChanged>,
+    mut surface_needs_reconfigure: EventWriter<WindowSurfaceNeedsReconfigure>,
 ) {
     for (entity, mut window, mut cache) in &mut changed_windows {
+        // Explicit visibility changes take precedence over `Window::visible_on_first_frame`.
+        if window.visible != cache.window.visible {
+            winit_windows.hidden_until_first_frame.remove(&entity);
+        }
+        if window.shape_mask != cache.window.shape_mask {
+            let regions = window
+                .shape_mask
+                .as_ref()
+                .map(|shape_mask| shape_mask.polygons.clone())
+                .unwrap_or_default();
+            winit_windows.set_hittest_mask(entity, regions);
+        }
+        if window.resize_constraints != cache.window.resize_constraints {
+            winit_windows.set_resize_constraints(entity, window.resize_constraints);
+        }
+
         let Some(winit_window) = winit_windows.get_window(entity) else {
             continue;
         };
+        let mut resized_surface = false;
+        // A failed grab to retry once the window gains focus, or `None` to stop retrying.
+        let mut grab_retry = None;
 
-        if window.title != cache.window.title {
-            winit_window.set_title(window.title.as_str());
+        let title_changed = window.title != cache.window.title;
+        if title_changed {
+            winit_window.set_title(&winit_windows.displayed_title(entity, &window.title));
         }
 
-        if window.mode != cache.window.mode {
-            let new_mode = match window.mode {
+        // Simple fullscreen has to be left before and entered after changing the native
+        // fullscreen state, since macOS doesn't allow both at the same time.
+        #[cfg(target_os = "macos")]
+        let (simple_fullscreen, was_simple_fullscreen) = (
+            window.simple_fullscreen && matches!(window.mode, WindowMode::BorderlessFullscreen(_)),
+            cache.window.simple_fullscreen
+                && matches!(cache.window.mode, WindowMode::BorderlessFullscreen(_)),
+        );
+        #[cfg(target_os = "macos")]
+        if was_simple_fullscreen && !simple_fullscreen {
+            use winit::platform::macos::WindowExtMacOS;
+            // This restores the prior windowed placement.
+            winit_window.set_simple_fullscreen(false);
+        }
+
+        #[cfg(target_os = "macos")]
+        if window.tabbing_identifier != cache.window.tabbing_identifier {
+            use winit::platform::macos::WindowExtMacOS;
+            winit_window.set_tabbing_identifier(window.tabbing_identifier.as_deref().unwrap_or(""));
+        }
+
+        if window.mode != cache.window.mode
+            || window.simple_fullscreen != cache.window.simple_fullscreen
+        {
+            let new_mode = match &window.mode {
+                #[cfg(target_os = "macos")]
+                WindowMode::BorderlessFullscreen(_) if window.simple_fullscreen => Some(None),
                 WindowMode::BorderlessFullscreen(monitor_selection) => {
                     Some(Some(winit::window::Fullscreen::Borderless(select_monitor(
                         &monitors,
                         winit_window.primary_monitor(),
-                        winit_window.current_monitor(),
-                        &monitor_selection,
+                        window_current_monitor(&monitors, winit_window),
+                        monitor_selection,
                     ))))
                 }
-                mode @ (WindowMode::Fullscreen(_) | WindowMode::SizedFullscreen(_)) => {
-                    let videomode = match mode {
-                        WindowMode::Fullscreen(monitor_selection) => get_best_videomode(
-                            &select_monitor(
-                                &monitors,
-                                winit_window.primary_monitor(),
-                                winit_window.current_monitor(),
-                                &monitor_selection,
-                            )
-                            .unwrap_or_else(|| {
-                                panic!("Could not find monitor for {:?}", monitor_selection)
-                            }),
-                        ),
-                        WindowMode::SizedFullscreen(monitor_selection) => get_fitting_videomode(
-                            &select_monitor(
+                WindowMode::Fullscreen(monitor_selection)
+                | WindowMode::SizedFullscreen(monitor_selection) => {
+                    let monitor = select_monitor(
+                        &monitors,
+                        winit_window.primary_monitor(),
+                        window_current_monitor(&monitors, winit_window),
+                        monitor_selection,
+                    );
+                    match fullscreen_monitor_fallback(
+                        window.mode.clone(),
+                        monitor,
+                        winit_window.primary_monitor(),
+                    ) {
+                        (WindowMode::Fullscreen(_), Some(monitor)) => {
+                            let videomode = get_best_videomode(&monitors, &monitor, None);
+                            Some(Some(exclusive_or_borderless(videomode, &monitor)))
+                        }
+                        (WindowMode::SizedFullscreen(_), Some(monitor)) => {
+                            let videomode = get_fitting_videomode(
                                 &monitors,
-                                winit_window.primary_monitor(),
-                                winit_window.current_monitor(),
-                                &monitor_selection,
-                            )
-                            .unwrap_or_else(|| {
-                                panic!("Could not find monitor for {:?}", monitor_selection)
-                            }),
-                            window.width() as u32,
-                            window.height() as u32,
-                        ),
-                        _ => unreachable!(),
-                    };
-
-                    Some(Some(winit::window::Fullscreen::Exclusive(videomode)))
+                                &monitor,
+                                window.width() as u32,
+                                window.height() as u32,
+                                None,
+                            );
+                            Some(Some(exclusive_or_borderless(videomode, &monitor)))
+                        }
+                        (WindowMode::BorderlessFullscreen(_), monitor) => {
+                            Some(Some(winit::window::Fullscreen::Borderless(monitor)))
+                        }
+                        _ => Some(None),
+                    }
                 }
                 WindowMode::Windowed => Some(None),
             };
@@ -324,6 +1242,12 @@ pub(crate) fn changed_windows(
             }
         }
 
+        #[cfg(target_os = "macos")]
+        if simple_fullscreen && !was_simple_fullscreen {
+            use winit::platform::macos::WindowExtMacOS;
+            winit_window.set_simple_fullscreen(true);
+        }
+
         if window.resolution != cache.window.resolution {
             let mut physical_size = PhysicalSize::new(
                 window.resolution.physical_width(),
@@ -365,10 +1289,15 @@ pub(crate) fn changed_windows(
             if physical_size != cached_physical_size {
                 if let Some(new_physical_size) = winit_window.request_inner_size(physical_size) {
                     react_to_resize(entity, &mut window, new_physical_size, &mut window_resized);
+                    resized_surface = true;
                 }
             }
         }
 
+        if window.max_surface_size != cache.window.max_surface_size {
+            resized_surface = true;
+        }
+
         if window.physical_cursor_position() != cache.window.physical_cursor_position() {
             if let Some(physical_position) = window.physical_cursor_position() {
                 let position = PhysicalPosition::new(physical_position.x, physical_position.y);
@@ -380,14 +1309,34 @@ pub(crate) fn changed_windows(
         }
 
         if window.cursor_options.grab_mode != cache.window.cursor_options.grab_mode
-            && crate::winit_windows::attempt_grab(winit_window, window.cursor_options.grab_mode)
-                .is_err()
+            || window.cursor_options.confine_region != cache.window.cursor_options.confine_region
         {
-            window.cursor_options.grab_mode = cache.window.cursor_options.grab_mode;
+            match attempt_grab(
+                winit_window,
+                window.cursor_options.grab_mode,
+                window.cursor_options.confine_region,
+            ) {
+                Ok(grab_mode) => {
+                    grab_retry = Some(None);
+                    cursor_grab_changed.send(CursorGrabChanged {
+                        window: entity,
+                        grab_mode,
+                    });
+                }
+                Err(_) => {
+                    let requested = (
+                        window.cursor_options.grab_mode,
+                        window.cursor_options.confine_region,
+                    );
+                    grab_retry = Some((requested.0 != CursorGrabMode::None).then_some(requested));
+                    window.cursor_options.grab_mode = cache.window.cursor_options.grab_mode;
+                }
+            }
         }
 
-        if window.cursor_options.visible != cache.window.cursor_options.visible {
-            winit_window.set_cursor_visible(window.cursor_options.visible);
+        if window.cursor_options.is_cursor_shown() != cache.window.cursor_options.is_cursor_shown()
+        {
+            winit_window.set_cursor_visible(window.cursor_options.is_cursor_shown());
         }
 
         if window.cursor_options.hit_test != cache.window.cursor_options.hit_test {
@@ -403,7 +1352,23 @@ pub(crate) fn changed_windows(
         if window.decorations != cache.window.decorations
             && window.decorations != winit_window.is_decorated()
         {
-            winit_window.set_decorations(window.decorations);
+            set_decorations_keeping_inner_size(winit_window, window.decorations);
+        }
+
+        let changed_color =
+            |new: WindowChromeColor, old: WindowChromeColor| (new != old).then_some(new);
+        set_chrome_colors(
+            winit_window,
+            changed_color(window.border_color, cache.window.border_color),
+            changed_color(
+                window.title_background_color,
+                cache.window.title_background_color,
+            ),
+            changed_color(window.title_text_color, cache.window.title_text_color),
+        );
+
+        if window.system_backdrop != cache.window.system_backdrop {
+            set_backdrop(winit_window, window.system_backdrop);
         }
 
         if window.resizable != cache.window.resizable
@@ -416,27 +1381,11 @@ pub(crate) fn changed_windows(
             winit_window.set_enabled_buttons(convert_enabled_buttons(window.enabled_buttons));
         }
 
-        if window.resize_constraints != cache.window.resize_constraints {
-            let constraints = window.resize_constraints.check_constraints();
-            let min_inner_size = LogicalSize {
-                width: constraints.min_width,
-                height: constraints.min_height,
-            };
-            let max_inner_size = LogicalSize {
-                width: constraints.max_width,
-                height: constraints.max_height,
-            };
-
-            winit_window.set_min_inner_size(Some(min_inner_size));
-            if constraints.max_width.is_finite() && constraints.max_height.is_finite() {
-                winit_window.set_max_inner_size(Some(max_inner_size));
-            }
-        }
-
         if window.position != cache.window.position {
             if let Some(position) = crate::winit_window_position(
                 &window.position,
                 &window.resolution,
+                window.keep_on_screen,
                 &monitors,
                 winit_window.primary_monitor(),
                 winit_window.current_monitor(),
@@ -479,7 +1428,8 @@ pub(crate) fn changed_windows(
         }
 
         if window.window_level != cache.window.window_level {
-            winit_window.set_window_level(convert_window_level(window.window_level));
+            winit_window
+                .set_window_level(convert_window_level(window.window_level, window.focused));
         }
 
         // Currently unsupported changes
@@ -515,6 +1465,18 @@ pub(crate) fn changed_windows(
             winit_window.set_visible(window.visible);
         }
 
+        if window.skip_taskbar != cache.window.skip_taskbar {
+            #[cfg(target_os = "windows")]
+            {
+                use winit::platform::windows::WindowExtWindows;
+                winit_window.set_skip_taskbar(window.skip_taskbar);
+            }
+            #[cfg(not(target_os = "windows"))]
+            if window.skip_taskbar {
+                bevy_log::warn_once!("`Window::skip_taskbar` is only supported on Windows");
+            }
+        }
+
         #[cfg(target_os = "ios")]
         {
             if window.recognize_pinch_gesture != cache.window.recognize_pinch_gesture {
@@ -547,6 +1509,33 @@ pub(crate) fn changed_windows(
                 winit_window.set_prefers_status_bar_hidden(window.prefers_status_bar_hidden);
             }
         }
+        if title_changed {
+            // The new title replaces a name set with `WinitWindows::set_title`.
+            winit_windows.accessibility_names.remove(&entity);
+            winit_windows.renamed_windows.insert(entity);
+            winit_windows.titles.insert(entity, window.title.clone());
+        }
         cache.window = window.clone();
+
+        if resized_surface {
+            let (physical_size, surface_size) = (window.physical_size(), window.surface_size());
+            if winit_windows.update_surface_size(entity, physical_size, surface_size) {
+                surface_needs_reconfigure.send(WindowSurfaceNeedsReconfigure {
+                    window: entity,
+                    physical_size,
+                    surface_size,
+                });
+            }
+        }
+
+        match grab_retry {
+            Some(Some(requested)) => {
+                winit_windows.pending_grabs.insert(entity, requested);
+            }
+            Some(None) => {
+                winit_windows.pending_grabs.remove(&entity);
+            }
+            None => {}
+        }
     }
 }
@@ -1,5 +1,12 @@
-use bevy_ecs::resource::Resource;
-use core::time::Duration;
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{entity::Entity, resource::Resource};
+use bevy_math::{IVec2, UVec2};
+use bevy_platform_support::collections::HashMap;
+use bevy_window::{Window, WindowResolution};
+use core::{fmt, time::Duration};
+use winit::window::{Icon, WindowAttributes};
+
+use crate::MonitorInfo;
 
 /// Settings for the [`WinitPlugin`](super::WinitPlugin).
 #[derive(Debug, Resource, Clone)]
@@ -123,3 +130,257 @@ impl UpdateMode {
         }
     }
 }
+
+/// Delays scale factor changes reported by `winit` until a window has settled on a monitor.
+///
+/// While a window straddles two monitors with different scale factors, the reported scale
+/// factor can flip back and forth as the window is dragged across the seam, each flip causing an
+/// expensive re-layout and surface reconfiguration. With this enabled, a new scale factor is
+/// only applied to the [`Window`](bevy_window::Window) once the monitor containing the largest
+/// part of the window has had that scale factor for [`settle_frames`](Self::settle_frames)
+/// consecutive updates. Resizes reported in the meantime are held back as well and applied
+/// together with the scale factor, so the logical resolution doesn't change in between.
+///
+/// Changes are applied immediately if the window's position is unknown, e.g. on Wayland.
+///
+/// This is enabled by default. Disable it if the window must follow the platform's scale
+/// factor immediately, since it keeps its old resolution for a few updates after the platform
+/// has resized it for the new scale factor.
+#[derive(Debug, Resource, Clone, Copy, PartialEq, Eq)]
+pub struct ScaleFactorHysteresis {
+    /// Whether scale factor changes are delayed. Defaults to `true`.
+    pub enabled: bool,
+    /// The number of consecutive updates the window must spend mostly on a monitor with the new
+    /// scale factor before it is applied. Defaults to `2`.
+    pub settle_frames: u32,
+}
+
+impl Default for ScaleFactorHysteresis {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            settle_frames: 2,
+        }
+    }
+}
+
+/// A callback that customizes the `winit` [`WindowAttributes`] of every window just before it is
+/// created, e.g. to set a platform-specific attribute that [`Window`] doesn't expose.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_winit::WindowAttributesHook;
+/// # let mut app = App::new();
+/// app.insert_resource(WindowAttributesHook::new(|_entity, _window, attributes| {
+///     attributes.content_protected = true;
+/// }));
+/// ```
+///
+/// The callback runs after all [`Window`] settings have been applied, so it can override them.
+/// Attributes that Bevy changes after creation, such as the visibility of windows kept hidden
+/// until their first frame, may still be changed afterwards.
+#[derive(Resource)]
+pub struct WindowAttributesHook(
+    Box<dyn Fn(Entity, &Window, &mut WindowAttributes) + Send + Sync + 'static>,
+);
+
+impl WindowAttributesHook {
+    /// Creates a hook calling `hook` with the window entity, its [`Window`] and its attributes.
+    pub fn new(
+        hook: impl Fn(Entity, &Window, &mut WindowAttributes) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(hook))
+    }
+
+    pub(crate) fn apply(&self, entity: Entity, window: &Window, attributes: &mut WindowAttributes) {
+        (self.0)(entity, window, attributes);
+    }
+}
+
+impl fmt::Debug for WindowAttributesHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowAttributesHook")
+            .finish_non_exhaustive()
+    }
+}
+
+/// An icon applied to every window when it is created, e.g. to brand all windows of a multi-window
+/// app with the app icon.
+///
+/// The icon is validated once when it is created, and a malformed icon is never applied.
+///
+/// ## Platform-specific
+///
+/// - **Windows / X11:** Shown in the title bar and the taskbar.
+/// - **Others:** Unsupported, windows take their icon from the app bundle or desktop entry.
+#[derive(Resource, Debug, Clone)]
+pub struct DefaultWindowIcon {
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+    valid: bool,
+}
+
+impl DefaultWindowIcon {
+    /// Creates an icon from `width * height` pixels of 8-bit RGBA data, in row-major order.
+    ///
+    /// Logs a warning if the buffer doesn't match the size, in which case the icon is ignored.
+    pub fn new(rgba: Vec<u8>, width: u32, height: u32) -> Self {
+        let valid = match Icon::from_rgba(rgba.clone(), width, height) {
+            Ok(_) => true,
+            Err(err) => {
+                bevy_log::warn!("Ignoring malformed default window icon: {}", err);
+                false
+            }
+        };
+        Self {
+            rgba,
+            width,
+            height,
+            valid,
+        }
+    }
+
+    /// Returns whether the icon is well-formed and will be applied to new windows.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    pub(crate) fn icon(&self) -> Option<Icon> {
+        if !self.valid {
+            return None;
+        }
+        Icon::from_rgba(self.rgba.clone(), self.width, self.height).ok()
+    }
+}
+
+/// A monitor assumed for window placement when no real monitor is available, e.g. in headless
+/// or CI runs without a display server.
+///
+/// Without this resource, [`WindowPosition::Centered`](bevy_window::WindowPosition::Centered)
+/// windows are left to the window manager when there are no monitors. With it, windows are
+/// centered on and kept on this monitor, so window placement is deterministic.
+#[derive(Debug, Resource, Clone, Copy, PartialEq)]
+pub struct VirtualMonitor {
+    /// The physical position of the top-left corner of the monitor in desktop coordinates.
+    pub position: IVec2,
+    /// The physical size of the monitor.
+    pub size: UVec2,
+    /// The scale factor of the monitor.
+    pub scale_factor: f64,
+}
+
+impl VirtualMonitor {
+    /// Returns the scale factor of a window with the given resolution on this monitor.
+    pub(crate) fn resolve_scale_factor(&self, resolution: &WindowResolution) -> f64 {
+        resolution
+            .scale_factor_override()
+            .map_or(self.scale_factor, f64::from)
+    }
+}
+
+impl Default for VirtualMonitor {
+    fn default() -> Self {
+        Self {
+            position: IVec2::ZERO,
+            size: UVec2::new(1920, 1080),
+            scale_factor: 1.0,
+        }
+    }
+}
+
+/// The fraction of a monitor's size a window gets on a monitor it has never been on, see
+/// [`WindowMemory`].
+const UNSEEN_MONITOR_FRACTION: f32 = 0.75;
+
+/// Remembers the geometry the primary window last had on each monitor, e.g. so a game that is
+/// moved between monitors keeps a separate window size on each.
+///
+/// While this resource exists, the position and size of the windowed
+/// [`PrimaryWindow`](bevy_window::PrimaryWindow) are recorded whenever it is moved or resized.
+/// Monitors are identified by their [`MonitorInfo`], or by their name if they report no
+/// identification data. Call [`WinitWindows::restore_for_current_monitor`](crate::WinitWindows::restore_for_current_monitor)
+/// to apply the remembered geometry, e.g. on [`WindowMovedToMonitor`](bevy_window::WindowMovedToMonitor).
+#[derive(Debug, Resource, Clone, Default, PartialEq)]
+pub struct WindowMemory {
+    geometries: HashMap<MonitorInfo, RememberedGeometry>,
+}
+
+/// The geometry of a window on a monitor, see [`WindowMemory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RememberedGeometry {
+    /// The physical position of the window's top-left corner, including decorations, relative
+    /// to the top-left corner of the monitor.
+    pub offset: IVec2,
+    /// The physical inner size of the window.
+    pub size: UVec2,
+}
+
+impl WindowMemory {
+    /// Returns the geometry remembered for the monitor, if any.
+    pub fn get(&self, monitor: &MonitorInfo) -> Option<RememberedGeometry> {
+        self.geometries.get(monitor).copied()
+    }
+
+    /// Remembers the geometry for the monitor, e.g. when loading saved settings.
+    pub fn remember(&mut self, monitor: MonitorInfo, geometry: RememberedGeometry) {
+        self.geometries.insert(monitor, geometry);
+    }
+
+    /// Returns an iterator over the monitors and their remembered geometry, e.g. to save them.
+    pub fn iter(&self) -> impl Iterator<Item = (&MonitorInfo, &RememberedGeometry)> {
+        self.geometries.iter()
+    }
+
+    /// Returns the physical position and inner size a window should have on the monitor with
+    /// the given bounds.
+    ///
+    /// Monitors without remembered geometry get a window centered at a fraction of their size.
+    pub(crate) fn geometry_on(
+        &self,
+        monitor: Option<&MonitorInfo>,
+        monitor_position: IVec2,
+        monitor_size: UVec2,
+    ) -> (IVec2, UVec2) {
+        if let Some(geometry) = monitor.and_then(|monitor| self.get(monitor)) {
+            return (monitor_position + geometry.offset, geometry.size);
+        }
+        let size = (monitor_size.as_vec2() * UNSEEN_MONITOR_FRACTION)
+            .as_uvec2()
+            .max(UVec2::ONE);
+        let offset = (monitor_size.as_ivec2() - size.as_ivec2()) / 2;
+        (monitor_position + offset, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_memory_defaults_to_centered_on_unseen_monitors() {
+        let mut memory = WindowMemory::default();
+        let monitor = MonitorInfo {
+            model: Some("DELL U2720Q".into()),
+            ..Default::default()
+        };
+        let position = IVec2::new(1920, 0);
+        let size = UVec2::new(2560, 1440);
+        assert_eq!(
+            memory.geometry_on(Some(&monitor), position, size),
+            (IVec2::new(1920 + 320, 180), UVec2::new(1920, 1080))
+        );
+
+        memory.remember(
+            monitor.clone(),
+            RememberedGeometry {
+                offset: IVec2::new(100, 50),
+                size: UVec2::new(1280, 720),
+            },
+        );
+        assert_eq!(
+            memory.geometry_on(Some(&monitor), position, size),
+            (IVec2::new(2020, 50), UVec2::new(1280, 720))
+        );
+    }
+}
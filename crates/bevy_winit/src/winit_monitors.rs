@@ -1,6 +1,42 @@
-use winit::monitor::MonitorHandle;
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+};
+use winit::monitor::{MonitorHandle, VideoModeHandle};
 
-use bevy_ecs::{entity::Entity, resource::Resource};
+use bevy_ecs::{
+    entity::{hash_map::EntityHashMap, Entity},
+    resource::Resource,
+};
+use bevy_math::{IRect, IVec2, UVec2, Vec2};
+use bevy_window::MonitorSelection;
+
+#[cfg(all(
+    feature = "x11",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+use bevy_platform_support::collections::HashMap;
+use bevy_platform_support::time::Instant;
+
+#[cfg(all(
+    feature = "x11",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+use crate::x11::GammaRamp;
+use crate::{select_monitor, VirtualMonitor};
 
 /// Stores [`winit`] monitors and their corresponding entities
 ///
@@ -18,17 +54,555 @@ pub struct WinitMonitors {
     // on some platforms. Using a `Vec` is fine because we don't expect to have a large number of
     // monitors and avoids having to audit the code for `MonitorHandle` equality.
     pub(crate) monitors: Vec<(MonitorHandle, Entity)>,
+    /// Caches the video modes of each monitor entity, since enumerating them can be expensive.
+    ///
+    /// Filled when a monitor is connected, refreshed periodically and cleared when it is
+    /// disconnected.
+    pub(crate) video_modes: EntityHashMap<Vec<VideoModeHandle>>,
+    /// The physical size in millimeters of each monitor entity whose size is known.
+    pub(crate) physical_sizes: EntityHashMap<Vec2>,
+    /// Identification data of each monitor entity, read when the monitor is connected.
+    pub(crate) infos: EntityHashMap<MonitorInfo>,
+    /// The monitor entity marked with [`PrimaryMonitor`](bevy_window::PrimaryMonitor).
+    pub(crate) primary: Option<Entity>,
+    /// The [`VirtualMonitor`] resource, copied whenever monitors are updated.
+    pub(crate) virtual_monitor: Option<VirtualMonitor>,
+    /// When the video modes of known monitors were last checked for changes.
+    pub(crate) last_video_mode_check: Option<Instant>,
+    /// Whether the event loop was built with the X11 backend, whose monitors are `RandR` CRTCs.
+    #[cfg(all(
+        feature = "x11",
+        any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )
+    ))]
+    pub(crate) is_x11: bool,
+    /// The gamma ramps of `RandR` CRTCs before [`WinitMonitors::set_gamma`] first changed them.
+    #[cfg(all(
+        feature = "x11",
+        any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        )
+    ))]
+    pub(crate) original_gamma_ramps: HashMap<u32, GammaRamp>,
 }
 
 impl WinitMonitors {
+    /// Returns the `n`th known monitor, in the order they were connected.
     pub fn nth(&self, n: usize) -> Option<MonitorHandle> {
         self.monitors.get(n).map(|(monitor, _)| monitor.clone())
     }
 
+    /// Returns the monitor of the given [`Monitor`](bevy_window::Monitor) entity.
     pub fn find_entity(&self, entity: Entity) -> Option<MonitorHandle> {
         self.monitors
             .iter()
             .find(|(_, e)| *e == entity)
             .map(|(monitor, _)| monitor.clone())
     }
+
+    /// Returns the [`Monitor`](bevy_window::Monitor) entity of the given monitor.
+    pub fn find_monitor_entity(&self, monitor: &MonitorHandle) -> Option<Entity> {
+        self.monitors
+            .iter()
+            .find(|(m, _)| m == monitor)
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Returns the [`PrimaryMonitor`](bevy_window::PrimaryMonitor) entity, if any.
+    ///
+    /// This is updated from the operating system's primary monitor every time the event loop is
+    /// about to wait.
+    pub fn primary(&self) -> Option<Entity> {
+        self.primary
+    }
+
+    /// Returns the [`VirtualMonitor`] assumed for window placement, if there are no monitors
+    /// and the resource has been inserted.
+    pub fn virtual_monitor(&self) -> Option<&VirtualMonitor> {
+        self.virtual_monitor
+            .as_ref()
+            .filter(|_| self.monitors.is_empty())
+    }
+
+    /// Returns the first monitor with the given name.
+    pub fn find_name(&self, name: &str) -> Option<MonitorHandle> {
+        self.monitors
+            .iter()
+            .find(|(monitor, _)| monitor.name().as_deref() == Some(name))
+            .map(|(monitor, _)| monitor.clone())
+    }
+
+    /// Returns the video modes of a monitor.
+    ///
+    /// The modes of known monitors are cached when the monitor is connected, so this only
+    /// enumerates them if the monitor isn't (or not yet) known.
+    pub fn video_modes(&self, monitor: &MonitorHandle) -> Cow<'_, [VideoModeHandle]> {
+        self.monitors
+            .iter()
+            .find(|(m, _)| m == monitor)
+            .and_then(|(_, entity)| self.video_modes.get(entity))
+            .map_or_else(
+                || Cow::Owned(monitor.video_modes().collect()),
+                |modes| Cow::Borrowed(modes.as_slice()),
+            )
+    }
+
+    /// Returns the physical size of a monitor entity in millimeters, e.g. to scale UI by
+    /// real-world size rather than pixel density.
+    ///
+    /// The size is read from the monitor's EDID data, with centimeter precision. Returns `None`
+    /// if the size is unknown, e.g. for projectors.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Linux:** Read from the DRM connector of the monitor, which is unavailable in some
+    ///   sandboxes.
+    /// - **Other platforms:** Unsupported, always `None`.
+    pub fn monitor_physical_size(&self, entity: Entity) -> Option<Vec2> {
+        self.physical_sizes.get(&entity).copied()
+    }
+
+    /// Returns the monitor whose bounds contain the given point, in physical desktop coordinates.
+    ///
+    /// Points on the right or bottom edge of a monitor belong to the neighboring monitor, if any.
+    pub fn monitor_at(&self, point: IVec2) -> Option<Entity> {
+        self.monitors
+            .iter()
+            .find(|(monitor, _)| {
+                let bounds = monitor_bounds(monitor);
+                point.cmpge(bounds.min).all() && point.cmplt(bounds.max).all()
+            })
+            .map(|(_, entity)| *entity)
+    }
+
+    /// Returns the monitor that contains the largest part of the given rectangle, in physical
+    /// desktop coordinates, e.g. the monitor a window is mostly on.
+    ///
+    /// Returns `None` if the rectangle doesn't overlap any monitor.
+    pub fn monitor_containing_largest_area(&self, rect: IRect) -> Option<Entity> {
+        self.monitors
+            .iter()
+            .map(|(monitor, entity)| {
+                let overlap = monitor_bounds(monitor).intersect(rect).size();
+                (i64::from(overlap.x) * i64::from(overlap.y), *entity)
+            })
+            .filter(|(area, _)| *area > 0)
+            .max_by_key(|(area, _)| *area)
+            .map(|(_, entity)| entity)
+    }
+
+    /// Returns the manufacturer, model and serial number of a monitor entity, e.g. to recognize
+    /// a monitor across reconnects more reliably than by its name.
+    ///
+    /// Returns `None` if the entity isn't a known monitor. Fields the platform or monitor doesn't
+    /// report are `None`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Linux:** Read from the EDID data of the DRM connector named like the monitor. This
+    ///   works for most Wayland compositors, but not for X11 outputs whose names differ from
+    ///   their connector (e.g. `HDMI-1` instead of `HDMI-A-1`).
+    /// - **Others:** Unsupported, all fields are `None`.
+    pub fn monitor_info(&self, entity: Entity) -> Option<&MonitorInfo> {
+        self.infos.get(&entity)
+    }
+
+    /// Returns the minimum and maximum corners of the rectangle spanning all monitors, in
+    /// physical desktop coordinates, e.g. to check that a saved window position is still on
+    /// screen.
+    ///
+    /// Monitors left of or above the primary monitor have negative coordinates, so the minimum
+    /// corner may be negative. Gaps between monitors are included in the rectangle. Returns
+    /// `None` if there are no monitors.
+    pub fn virtual_screen_rect(&self) -> Option<(IVec2, IVec2)> {
+        self.monitors
+            .iter()
+            .map(|(monitor, _)| monitor_bounds(monitor))
+            .reduce(|a, b| a.union(b))
+            .map(|rect| (rect.min, rect.max))
+    }
+
+    /// Moves a rectangle at `position` with the given `size`, in physical desktop coordinates,
+    /// so it lies entirely on a monitor, e.g. to keep a restored window from opening off-screen.
+    ///
+    /// The rectangle is moved onto the monitor it overlaps the most, or onto the nearest monitor
+    /// if it is off-screen. If it is larger than that monitor, its top-left corner is kept on the
+    /// monitor. Without monitors, the [`VirtualMonitor`] is used, if any, and the position is
+    /// returned unchanged otherwise.
+    pub fn clamp_to_monitors(&self, position: IVec2, size: UVec2) -> IVec2 {
+        if let Some(virtual_monitor) = self.virtual_monitor() {
+            let bounds = IRect::from_corners(
+                virtual_monitor.position,
+                virtual_monitor.position + virtual_monitor.size.as_ivec2(),
+            );
+            return clamp_to_bounds(position, size, core::iter::once(bounds));
+        }
+        clamp_to_bounds(
+            position,
+            size,
+            self.monitors
+                .iter()
+                .map(|(monitor, _)| monitor_bounds(monitor)),
+        )
+    }
+
+    /// Sets the gamma of the selected monitor, e.g. for an in-game brightness slider.
+    ///
+    /// `gamma` is applied on top of the monitor's original gamma ramp, so `1.0` restores it,
+    /// values above brighten and values below darken the display. It is clamped to
+    /// `0.1..=10.0`. The original ramps are restored when an [`AppExit`](bevy_app::AppExit)
+    /// event is sent, so the user's display isn't left altered.
+    ///
+    /// Returns `false` if the monitor can't be found or the ramp couldn't be changed, and logs a
+    /// warning once if the platform isn't supported. [`MonitorSelection::Current`] has no window
+    /// to resolve against and is never found.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sets the `RandR` gamma ramp of the monitor's CRTC. Requires the `x11` feature.
+    /// - **Windows / macOS / Wayland / iOS / Android / Web:** Unsupported.
+    pub fn set_gamma(&mut self, monitor: MonitorSelection, gamma: f32) -> bool {
+        let primary = self.primary().and_then(|entity| self.find_entity(entity));
+        let Some(monitor) = select_monitor(self, primary, None, &monitor) else {
+            return false;
+        };
+        let gamma = gamma.clamp(0.1, 10.0);
+
+        #[cfg(all(
+            feature = "x11",
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            )
+        ))]
+        if self.is_x11 {
+            use winit::platform::x11::MonitorHandleExtX11;
+
+            let crtc = monitor.native_id();
+            let Some(x11) = crate::x11::connection() else {
+                return false;
+            };
+            let original = match self.original_gamma_ramps.get(&crtc) {
+                Some(original) => original,
+                None => {
+                    let Some(original) = x11.gamma_ramp(crtc) else {
+                        return false;
+                    };
+                    self.original_gamma_ramps.entry(crtc).or_insert(original)
+                }
+            };
+            return x11.set_gamma_ramp(crtc, &original.with_gamma(gamma));
+        }
+
+        let _ = (monitor, gamma);
+        bevy_log::warn_once!("`WinitMonitors::set_gamma` is unsupported on this platform");
+        false
+    }
+
+    /// Restores the gamma ramps changed with [`WinitMonitors::set_gamma`].
+    pub(crate) fn restore_gamma_ramps(&mut self) {
+        #[cfg(all(
+            feature = "x11",
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            )
+        ))]
+        if let Some(x11) = crate::x11::connection() {
+            for (crtc, ramp) in self.original_gamma_ramps.drain() {
+                x11.set_gamma_ramp(crtc, &ramp);
+            }
+        }
+    }
+}
+
+/// Returns the identity [`WindowMemory`](crate::WindowMemory) remembers a monitor by, falling
+/// back to its name if it reports no identification data.
+pub(crate) fn monitor_identity(
+    monitors: &WinitMonitors,
+    monitor: &MonitorHandle,
+) -> Option<MonitorInfo> {
+    let info = monitors
+        .find_monitor_entity(monitor)
+        .and_then(|entity| monitors.monitor_info(entity))
+        .filter(|info| **info != MonitorInfo::default());
+    match info {
+        Some(info) => Some(info.clone()),
+        None => monitor.name().map(|name| MonitorInfo {
+            model: Some(name),
+            ..Default::default()
+        }),
+    }
+}
+
+/// Moves a rectangle so it lies entirely within the bounds it overlaps the most, or the nearest
+/// bounds if it overlaps none, see [`WinitMonitors::clamp_to_monitors`].
+fn clamp_to_bounds(position: IVec2, size: UVec2, bounds: impl Iterator<Item = IRect>) -> IVec2 {
+    let size = size.as_ivec2();
+    let rect = IRect::from_corners(position, position + size);
+    let distance = |bounds: &IRect| {
+        let offset = rect.center().clamp(bounds.min, bounds.max) - rect.center();
+        i64::from(offset.x).pow(2) + i64::from(offset.y).pow(2)
+    };
+    let area = |bounds: &IRect| {
+        let overlap = bounds.intersect(rect).size();
+        i64::from(overlap.x) * i64::from(overlap.y)
+    };
+
+    let Some(bounds) = bounds.max_by(|a, b| {
+        area(a)
+            .cmp(&area(b))
+            .then_with(|| distance(b).cmp(&distance(a)))
+    }) else {
+        return position;
+    };
+    let max_position = bounds.min + (bounds.size() - size).max(IVec2::ZERO);
+    position.clamp(bounds.min, max_position)
+}
+
+/// Reads the physical size of a monitor in millimeters, if the platform reports it.
+///
+/// `winit` doesn't expose the physical size of monitors, so this is read from the EDID of the
+/// monitor where it is available.
+pub(crate) fn physical_size_mm(monitor: &MonitorHandle) -> Option<Vec2> {
+    #[cfg(target_os = "linux")]
+    if let Some(size) = monitor
+        .name()
+        .and_then(|name| read_drm_edid(&name))
+        .and_then(|edid| physical_size_mm_from_edid(&edid))
+    {
+        return Some(size);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = monitor;
+
+    None
+}
+
+/// Parses the physical size in millimeters from the base block of an EDID.
+///
+/// Returns `None` if `edid` isn't a valid EDID, or if the monitor doesn't report its size, e.g.
+/// projectors, which only report an aspect ratio.
+#[cfg_attr(
+    not(any(target_os = "linux", test)),
+    expect(dead_code, reason = "EDIDs are only read on Linux")
+)]
+fn physical_size_mm_from_edid(edid: &[u8]) -> Option<Vec2> {
+    if !is_edid(edid) {
+        return None;
+    }
+    // The size is stored in centimeters.
+    let (width, height) = (edid[21], edid[22]);
+    (width != 0 && height != 0).then(|| Vec2::new(width as f32, height as f32) * 10.0)
+}
+
+/// Returns whether `edid` starts with a complete EDID base block.
+fn is_edid(edid: &[u8]) -> bool {
+    const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+    edid.len() >= 128 && edid[..8] == HEADER
+}
+
+/// Identification data of a monitor, see [`WinitMonitors::monitor_info`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MonitorInfo {
+    /// The three-letter PNP manufacturer ID, e.g. `DEL`.
+    pub manufacturer: Option<String>,
+    /// The model name, or the hexadecimal product code if the monitor doesn't report a name.
+    pub model: Option<String>,
+    /// The serial number.
+    pub serial: Option<String>,
+}
+
+impl MonitorInfo {
+    /// Parses the identification data from the base block of an EDID.
+    ///
+    /// Returns `None` if `edid` isn't a valid EDID.
+    #[cfg_attr(
+        not(any(target_os = "linux", test)),
+        expect(dead_code, reason = "EDIDs are only read on Linux")
+    )]
+    pub(crate) fn from_edid(edid: &[u8]) -> Option<Self> {
+        if !is_edid(edid) {
+            return None;
+        }
+
+        // Three letters of five bits each, where 1 is `A`.
+        let id = u16::from_be_bytes([edid[8], edid[9]]);
+        let manufacturer = [10, 5, 0]
+            .into_iter()
+            .map(|shift| char::from(b'A' - 1 + ((id >> shift) & 0x1F) as u8))
+            .collect::<String>();
+        let product_code = u16::from_le_bytes([edid[10], edid[11]]);
+        let serial_number = u32::from_le_bytes([edid[12], edid[13], edid[14], edid[15]]);
+
+        // Display descriptors may hold the name and serial number as text.
+        let descriptor_text = |tag: u8| {
+            edid[54..126].chunks_exact(18).find_map(|descriptor| {
+                if descriptor[..3] != [0, 0, 0] || descriptor[3] != tag {
+                    return None;
+                }
+                let text = descriptor[5..]
+                    .iter()
+                    .take_while(|&&byte| byte != b'\n')
+                    .map(|&byte| char::from(byte))
+                    .collect::<String>();
+                let text = text.trim();
+                (!text.is_empty()).then(|| text.to_string())
+            })
+        };
+
+        Some(Self {
+            manufacturer: manufacturer
+                .chars()
+                .all(|c| c.is_ascii_uppercase())
+                .then_some(manufacturer),
+            model: descriptor_text(0xFC).or_else(|| Some(format!("{product_code:04X}"))),
+            serial: descriptor_text(0xFF)
+                .or_else(|| (serial_number != 0).then(|| serial_number.to_string())),
+        })
+    }
+}
+
+/// Reads the identification data of a monitor, if the platform reports it.
+pub(crate) fn monitor_info(monitor: &MonitorHandle) -> MonitorInfo {
+    #[cfg(target_os = "linux")]
+    if let Some(info) = monitor
+        .name()
+        .and_then(|name| read_drm_edid(&name))
+        .and_then(|edid| MonitorInfo::from_edid(&edid))
+    {
+        return info;
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = monitor;
+
+    MonitorInfo::default()
+}
+
+/// Reads the EDID of the DRM connector with the given name, e.g. `DP-1` for
+/// `/sys/class/drm/card0-DP-1/edid`.
+#[cfg(target_os = "linux")]
+fn read_drm_edid(connector: &str) -> Option<Vec<u8>> {
+    let suffix = format!("-{connector}");
+    std::fs::read_dir("/sys/class/drm")
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(&suffix))
+        .find_map(|entry| {
+            let edid = std::fs::read(entry.path().join("edid")).ok()?;
+            is_edid(&edid).then_some(edid)
+        })
+}
+
+/// Returns the bounds of a monitor in physical desktop coordinates.
+fn monitor_bounds(monitor: &MonitorHandle) -> IRect {
+    let position = monitor.position();
+    let size = monitor.size();
+    let min = IVec2::new(position.x, position.y);
+    IRect::from_corners(min, min + IVec2::new(size.width as i32, size.height as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_off_the_rightmost_monitor_are_clamped() {
+        let monitors = [
+            IRect::new(0, 0, 1920, 1080),
+            IRect::new(1920, 0, 3840, 1080),
+        ];
+        let size = UVec2::new(800, 600);
+        let clamp = |position| clamp_to_bounds(position, size, monitors.into_iter());
+
+        // Entirely off the right edge.
+        assert_eq!(clamp(IVec2::new(5000, 100)), IVec2::new(3040, 100));
+        // Partially off the right edge.
+        assert_eq!(clamp(IVec2::new(3500, 100)), IVec2::new(3040, 100));
+        // Off the bottom-right corner.
+        assert_eq!(clamp(IVec2::new(4000, 2000)), IVec2::new(3040, 480));
+        // Already on screen.
+        assert_eq!(clamp(IVec2::new(2000, 200)), IVec2::new(2000, 200));
+        // Larger than the monitor.
+        assert_eq!(
+            clamp_to_bounds(
+                IVec2::new(4000, 0),
+                UVec2::new(2500, 600),
+                monitors.into_iter()
+            ),
+            IVec2::new(1920, 0)
+        );
+        assert_eq!(
+            clamp_to_bounds(IVec2::new(5000, 100), size, core::iter::empty()),
+            IVec2::new(5000, 100)
+        );
+    }
+
+    #[test]
+    fn monitor_info_from_edid() {
+        let mut edid = [0; 128];
+        edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        // "DEL", product code 0xA0C3, serial number 12345.
+        edid[8..10].copy_from_slice(&[0x10, 0xAC]);
+        edid[10..12].copy_from_slice(&[0xC3, 0xA0]);
+        edid[12..16].copy_from_slice(&12345u32.to_le_bytes());
+        // A monitor name descriptor in the second descriptor slot.
+        edid[72..77].copy_from_slice(&[0, 0, 0, 0xFC, 0]);
+        edid[77..90].copy_from_slice(b"DELL U2720Q\n ");
+
+        assert_eq!(
+            MonitorInfo::from_edid(&edid),
+            Some(MonitorInfo {
+                manufacturer: Some("DEL".into()),
+                model: Some("DELL U2720Q".into()),
+                serial: Some("12345".into()),
+            })
+        );
+
+        edid[72..90].fill(0);
+        edid[12..16].fill(0);
+        assert_eq!(
+            MonitorInfo::from_edid(&edid),
+            Some(MonitorInfo {
+                manufacturer: Some("DEL".into()),
+                model: Some("A0C3".into()),
+                serial: None,
+            })
+        );
+
+        assert_eq!(MonitorInfo::from_edid(&edid[..64]), None);
+    }
+
+    #[test]
+    fn physical_size_from_edid() {
+        let mut edid = [0; 128];
+        edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        edid[21..23].copy_from_slice(&[60, 34]);
+        assert_eq!(
+            physical_size_mm_from_edid(&edid),
+            Some(Vec2::new(600.0, 340.0))
+        );
+
+        // Only an aspect ratio.
+        edid[22] = 0;
+        assert_eq!(physical_size_mm_from_edid(&edid), None);
+        assert_eq!(physical_size_mm_from_edid(&edid[..64]), None);
+    }
 }
@@ -1,16 +1,22 @@
+use alloc::borrow::Cow;
 use bevy_a11y::AccessibilityRequested;
-use bevy_ecs::entity::Entity;
+use bevy_ecs::{component::Component, entity::Entity};
+use core::{cmp::Reverse, time::Duration};
+use raw_window_handle::{HasDisplayHandle, HasWindowHandle, RawDisplayHandle, RawWindowHandle};
 
-use bevy_ecs::entity::hash_map::EntityHashMap;
-use bevy_platform_support::collections::HashMap;
+use bevy_ecs::entity::{hash_map::EntityHashMap, hash_set::EntityHashSet};
+use bevy_math::{CompassQuadrant, IRect, IVec2, Rect, UVec2, Vec2};
+use bevy_platform_support::{collections::HashMap, time::Instant};
 use bevy_window::{
-    CursorGrabMode, MonitorSelection, Window, WindowMode, WindowPosition, WindowResolution,
+    ActivationReason, CursorGrabMode, EnabledButtons, MonitorAnchor, MonitorSelection,
+    SystemCursorIcon, Window, WindowBackdrop, WindowChromeColor, WindowLevel, WindowMode,
+    WindowPosition, WindowPresentation, WindowResizeConstraints, WindowResolution, WindowTheme,
     WindowWrapper,
 };
 use tracing::warn;
 
 use winit::{
-    dpi::{LogicalSize, PhysicalPosition},
+    dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize},
     error::ExternalError,
     event_loop::ActiveEventLoop,
     monitor::{MonitorHandle, VideoModeHandle},
@@ -21,8 +27,12 @@ use crate::{
     accessibility::{
         prepare_accessibility_for_window, AccessKitAdapters, WinitActionRequestHandlers,
     },
-    converters::{convert_enabled_buttons, convert_window_level, convert_window_theme},
-    winit_monitors::WinitMonitors,
+    converters::{
+        convert_enabled_buttons, convert_system_cursor_icon, convert_window_level,
+        convert_window_theme,
+    },
+    winit_monitors::{monitor_identity, WinitMonitors},
+    WindowAttributesHook, WindowMemory, WinitBackend,
 };
 
 /// A resource mapping window entities to their `winit`-backend [`Window`](winit::window::Window)
@@ -35,6 +45,80 @@ pub struct WinitWindows {
     pub entity_to_winit: EntityHashMap<WindowId>,
     /// Maps `winit` window identifiers to entities.
     pub winit_to_entity: HashMap<WindowId, Entity>,
+    /// Windows that are kept hidden until their first frame has been rendered.
+    ///
+    /// See [`Window::visible_on_first_frame`].
+    pub(crate) hidden_until_first_frame: EntityHashSet,
+    /// The last known physical size and rendering surface size of each window.
+    pub(crate) surface_sizes: EntityHashMap<(UVec2, UVec2)>,
+    /// Windows that have presented at least one frame.
+    pub(crate) presented_first_frame: EntityHashSet,
+    /// The main window of each [`SplashWindow`], maintained by `close_splash_windows`.
+    pub(crate) splash_windows: EntityHashMap<Entity>,
+    /// The parent window blocked by each open [`ModalWindow`].
+    pub(crate) modal_parents: EntityHashMap<Entity>,
+    /// The title of each window, without the mark added by
+    /// [`WinitWindows::set_document_edited`].
+    pub(crate) titles: EntityHashMap<String>,
+    /// Windows marked as having unsaved changes, see [`WinitWindows::set_document_edited`].
+    pub(crate) edited_documents: EntityHashSet,
+    /// Cursor hit-test masks of windows, see [`WinitWindows::set_hittest_mask`].
+    pub(crate) hittest_masks: EntityHashMap<HittestMask>,
+    /// When a redraw was last requested for each window with a
+    /// [`Window::redraw_interval`].
+    pub(crate) last_redraw_requests: EntityHashMap<Instant>,
+    /// When each window with a [`Window::resize_redraw_delay`] was last resized, until its
+    /// coalesced redraw has been requested.
+    pub(crate) last_resizes: EntityHashMap<Instant>,
+    /// When each window was created or last received keyboard, mouse or touch input, see
+    /// [`WinitWindows::idle_duration`].
+    pub(crate) last_inputs: EntityHashMap<Instant>,
+    /// Names of `AccessKit` window nodes set with [`WinitWindows::set_title`].
+    pub(crate) accessibility_names: EntityHashMap<String>,
+    /// Windows whose `AccessKit` window node name changed since the last accessibility update.
+    pub(crate) renamed_windows: EntityHashSet,
+    /// The latest interactive resize or move of each window and when it was last reported, see
+    /// [`WinitWindows::interaction_state`].
+    pub(crate) interactions: EntityHashMap<(InteractionState, Instant)>,
+    /// Windows that have been sent a [`WindowReady`](bevy_window::WindowReady) event.
+    pub(crate) ready_windows: EntityHashSet,
+    /// Scale factor changes that wait for the window to settle on a monitor, see
+    /// [`ScaleFactorHysteresis`](crate::ScaleFactorHysteresis).
+    pub(crate) pending_scale_factors: EntityHashMap<PendingScaleFactor>,
+    /// The last known logical cursor position of each window the cursor is in, see
+    /// [`WinitWindows::cursor_position`].
+    pub(crate) cursor_positions: EntityHashMap<Vec2>,
+    /// The anchor each window is kept above, see [`WinitWindows::set_window_above`].
+    pub(crate) window_anchors: EntityHashMap<Entity>,
+    /// The grab mode that took effect for each window in relative mouse mode, see
+    /// [`WinitWindows::enable_relative_mouse`].
+    pub(crate) relative_mouse: EntityHashMap<CursorGrabMode>,
+    /// Cursor grabs that failed, e.g. because the window wasn't focused yet, along with their
+    /// confine region. They are retried whenever the window gains focus.
+    pub(crate) pending_grabs: EntityHashMap<(CursorGrabMode, Option<Rect>)>,
+    /// Windows whose close was requested and neither canceled nor confirmed yet, see
+    /// [`WinitWindows::confirm_close`].
+    pub(crate) pending_closes: EntityHashSet,
+    /// Windows whose pending close was confirmed and that will be closed next update.
+    pub(crate) confirmed_closes: EntityHashSet,
+    /// The last resize constraints applied to each window, see
+    /// [`WinitWindows::set_min_inner_size`].
+    pub(crate) resize_constraints: EntityHashMap<WindowResizeConstraints>,
+    /// Whether each window is active and why, see [`WinitWindows::activation`].
+    pub(crate) activations: EntityHashMap<(bool, ActivationReason)>,
+    /// Window entities from front to back, see [`WinitWindows::z_ordered_entities`].
+    pub(crate) z_order: Vec<Entity>,
+    /// Changes applied with [`WinitWindows::update_window`] that haven't been written back to
+    /// the [`Window`] yet.
+    pub(crate) window_updates: EntityHashMap<WindowUpdate>,
+    /// Resizes held back while a batch from [`WinitWindows::update_window`] settles.
+    pub(crate) held_resizes: EntityHashMap<HeldResize>,
+    /// Windows replaced by [`WinitWindows::recreate_window`], dropped by `despawn_windows`
+    /// one frame later like closed windows.
+    pub(crate) recreated_windows: Vec<WindowWrapper<WinitWindow>>,
+    /// The windowing backend the event loop was built with on Linux, see
+    /// [`WinitWindows::backend`].
+    pub(crate) backend: Option<WinitBackend>,
     // Many `winit` window functions (e.g. `set_window_icon`) can only be called on the main thread.
     // If they're called on other threads, the program might hang. This marker indicates that this
     // type is not thread-safe and will be `!Send` and `!Sync`.
@@ -43,6 +127,8 @@ pub struct WinitWindows {
 
 impl WinitWindows {
     /// Creates a `winit` window and associates it with our entity.
+    ///
+    /// The [`WindowAttributesHook`], if any, is applied last before the window is created.
     pub fn create_window(
         &mut self,
         event_loop: &ActiveEventLoop,
@@ -52,6 +138,7 @@ impl WinitWindows {
         handlers: &mut WinitActionRequestHandlers,
         accessibility_requested: &AccessibilityRequested,
         monitors: &WinitMonitors,
+        attributes_hook: Option<&WindowAttributesHook>,
     ) -> &WindowWrapper<WinitWindow> {
         let mut winit_window_attributes = WinitWindow::default_attributes();
 
@@ -59,52 +146,80 @@ impl WinitWindows {
         // AccessKit adapter is initialized.
         winit_window_attributes = winit_window_attributes.with_visible(false);
 
-        let maybe_selected_monitor = &match window.mode {
+        // A window that is being created has no monitor yet, so `MonitorSelection::Current`
+        // resolves to the monitor the user is working on instead.
+        let current_monitor = self
+            .focused_window_monitor()
+            .or_else(|| event_loop.primary_monitor());
+
+        let maybe_selected_monitor = match &window.mode {
             WindowMode::BorderlessFullscreen(monitor_selection)
             | WindowMode::Fullscreen(monitor_selection)
             | WindowMode::SizedFullscreen(monitor_selection) => select_monitor(
                 monitors,
                 event_loop.primary_monitor(),
-                None,
-                &monitor_selection,
+                current_monitor.clone(),
+                monitor_selection,
             ),
             WindowMode::Windowed => None,
         };
+        let (mode, maybe_selected_monitor) = fullscreen_monitor_fallback(
+            window.mode.clone(),
+            maybe_selected_monitor,
+            event_loop.primary_monitor(),
+        );
+        let maybe_selected_monitor = &maybe_selected_monitor;
 
-        winit_window_attributes = match window.mode {
-            WindowMode::BorderlessFullscreen(_) => winit_window_attributes
-                .with_fullscreen(Some(Fullscreen::Borderless(maybe_selected_monitor.clone()))),
-            WindowMode::Fullscreen(_) => {
-                let select_monitor = &maybe_selected_monitor
-                    .clone()
-                    .expect("Unable to get monitor.");
-                let videomode = get_best_videomode(select_monitor);
-                winit_window_attributes.with_fullscreen(Some(Fullscreen::Exclusive(videomode)))
-            }
-            WindowMode::SizedFullscreen(_) => {
-                let select_monitor = &maybe_selected_monitor
-                    .clone()
-                    .expect("Unable to get monitor.");
+        // Simple fullscreen can only be entered once the window exists, so it is created windowed.
+        let simple_fullscreen = cfg!(target_os = "macos")
+            && window.simple_fullscreen
+            && matches!(mode, WindowMode::BorderlessFullscreen(_));
+
+        winit_window_attributes = match (mode, maybe_selected_monitor) {
+            (WindowMode::BorderlessFullscreen(_), _) if !simple_fullscreen => {
+                winit_window_attributes
+                    .with_fullscreen(Some(Fullscreen::Borderless(maybe_selected_monitor.clone())))
+            }
+            (WindowMode::Fullscreen(_), Some(select_monitor)) => {
+                let videomode = get_best_videomode(monitors, select_monitor, None);
+                winit_window_attributes
+                    .with_fullscreen(Some(exclusive_or_borderless(videomode, select_monitor)))
+            }
+            (WindowMode::SizedFullscreen(_), Some(select_monitor)) => {
                 let videomode = get_fitting_videomode(
+                    monitors,
                     select_monitor,
                     window.width() as u32,
                     window.height() as u32,
+                    None,
                 );
-                winit_window_attributes.with_fullscreen(Some(Fullscreen::Exclusive(videomode)))
+                winit_window_attributes
+                    .with_fullscreen(Some(exclusive_or_borderless(videomode, select_monitor)))
             }
-            WindowMode::Windowed => {
+            // Exclusive fullscreen modes end up here if there is no monitor at all.
+            (
+                WindowMode::Windowed
+                | WindowMode::BorderlessFullscreen(_)
+                | WindowMode::Fullscreen(_)
+                | WindowMode::SizedFullscreen(_),
+                _,
+            ) => {
                 if let Some(position) = winit_window_position(
                     &window.position,
                     &window.resolution,
+                    window.keep_on_screen,
                     monitors,
                     event_loop.primary_monitor(),
-                    None,
+                    current_monitor,
                 ) {
                     winit_window_attributes = winit_window_attributes.with_position(position);
                 }
                 let logical_size = LogicalSize::new(window.width(), window.height());
-                if let Some(sf) = window.resolution.scale_factor_override() {
-                    let inner_size = logical_size.to_physical::<f64>(sf.into());
+                // Without an override, `winit` applies the scale factor of the monitor the
+                // window ends up on.
+                if window.resolution.scale_factor_override().is_some() {
+                    let scale_factor = resolve_scale_factor(&window.resolution, None);
+                    let inner_size = logical_size.to_physical::<f64>(scale_factor);
                     winit_window_attributes.with_inner_size(inner_size)
                 } else {
                     winit_window_attributes.with_inner_size(logical_size)
@@ -112,14 +227,21 @@ impl WinitWindows {
             }
         };
 
+        // `set_visible` activates the window on macOS, so windows that shouldn't take focus are
+        // shown on creation there instead of once the AccessKit adapter is initialized.
+        let visible = window.visible && !window.visible_on_first_frame;
+        let visible_on_creation = cfg!(target_os = "macos") && !window.focused;
+
         winit_window_attributes = winit_window_attributes
-            .with_window_level(convert_window_level(window.window_level))
+            .with_window_level(convert_window_level(window.window_level, window.focused))
             .with_theme(window.window_theme.map(convert_window_theme))
             .with_resizable(window.resizable)
             .with_enabled_buttons(convert_enabled_buttons(window.enabled_buttons))
             .with_decorations(window.decorations)
-            .with_transparent(window.transparent)
-            .with_visible(window.visible);
+            .with_transparent(window.transparent || window.shape_mask.is_some())
+            .with_maximized(window.start_maximized)
+            .with_active(window.focused)
+            .with_visible(visible && visible_on_creation);
 
         #[cfg(target_os = "windows")]
         {
@@ -128,6 +250,33 @@ impl WinitWindows {
                 winit_window_attributes.with_skip_taskbar(window.skip_taskbar);
             winit_window_attributes =
                 winit_window_attributes.with_clip_children(window.clip_children);
+            winit_window_attributes = winit_window_attributes
+                .with_undecorated_shadow(window.undecorated_shadow)
+                .with_corner_preference(crate::converters::convert_corner_preference(
+                    window.corner_preference,
+                ))
+                .with_border_color(crate::converters::convert_chrome_color(window.border_color))
+                .with_title_background_color(crate::converters::convert_chrome_color(
+                    window.title_background_color,
+                ))
+                .with_title_text_color(
+                    crate::converters::convert_chrome_color(window.title_text_color)
+                        .unwrap_or(winit::platform::windows::Color::SYSTEM_DEFAULT),
+                )
+                .with_system_backdrop(crate::converters::convert_system_backdrop(
+                    window.system_backdrop,
+                ));
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            winit_window_attributes =
+                winit_window_attributes.with_blur(window.system_backdrop.is_blurred());
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        if window.skip_taskbar {
+            bevy_log::warn_once!("`Window::skip_taskbar` is only supported on Windows");
         }
 
         #[cfg(target_os = "macos")]
@@ -141,6 +290,10 @@ impl WinitWindows {
                 .with_titlebar_transparent(window.titlebar_transparent)
                 .with_title_hidden(!window.titlebar_show_title)
                 .with_titlebar_buttons_hidden(!window.titlebar_show_buttons);
+            if let Some(tabbing_identifier) = &window.tabbing_identifier {
+                winit_window_attributes =
+                    winit_window_attributes.with_tabbing_identifier(tabbing_identifier);
+            }
         }
 
         #[cfg(target_os = "ios")]
@@ -152,22 +305,7 @@ impl WinitWindows {
                 .with_prefers_status_bar_hidden(window.prefers_status_bar_hidden);
         }
 
-        let display_info = DisplayInfo {
-            window_physical_resolution: (
-                window.resolution.physical_width(),
-                window.resolution.physical_height(),
-            ),
-            window_logical_resolution: (window.resolution.width(), window.resolution.height()),
-            monitor_name: maybe_selected_monitor
-                .as_ref()
-                .and_then(MonitorHandle::name),
-            scale_factor: maybe_selected_monitor
-                .as_ref()
-                .map(MonitorHandle::scale_factor),
-            refresh_rate_millihertz: maybe_selected_monitor
-                .as_ref()
-                .and_then(MonitorHandle::refresh_rate_millihertz),
-        };
+        let display_info = DisplayInfo::new(window, maybe_selected_monitor.as_ref());
         bevy_log::debug!("{display_info}");
 
         #[cfg(any(
@@ -189,12 +327,12 @@ impl WinitWindows {
                     target_os = "openbsd"
                 )
             ))]
-            {
+            if winit::platform::wayland::ActiveEventLoopExtWayland::is_wayland(event_loop) {
                 winit_window_attributes =
                     winit::platform::wayland::WindowAttributesExtWayland::with_name(
                         winit_window_attributes,
-                        name.clone(),
-                        "",
+                        name.general.clone(),
+                        name.instance.clone(),
                     );
             }
 
@@ -208,11 +346,11 @@ impl WinitWindows {
                     target_os = "openbsd"
                 )
             ))]
-            {
+            if winit::platform::x11::ActiveEventLoopExtX11::is_x11(event_loop) {
                 winit_window_attributes = winit::platform::x11::WindowAttributesExtX11::with_name(
                     winit_window_attributes,
-                    name.clone(),
-                    "",
+                    name.general.clone(),
+                    name.instance.clone(),
                 );
             }
             #[cfg(target_os = "windows")]
@@ -220,29 +358,20 @@ impl WinitWindows {
                 winit_window_attributes =
                     winit::platform::windows::WindowAttributesExtWindows::with_class_name(
                         winit_window_attributes,
-                        name.clone(),
+                        name.general.clone(),
                     );
             }
         }
 
-        let constraints = window.resize_constraints.check_constraints();
-        let min_inner_size = LogicalSize {
-            width: constraints.min_width,
-            height: constraints.min_height,
-        };
-        let max_inner_size = LogicalSize {
-            width: constraints.max_width,
-            height: constraints.max_height,
-        };
+        let (min_inner_size, max_inner_size) = inner_size_limits(&window.resize_constraints);
 
-        let winit_window_attributes =
-            if constraints.max_width.is_finite() && constraints.max_height.is_finite() {
-                winit_window_attributes
-                    .with_min_inner_size(min_inner_size)
-                    .with_max_inner_size(max_inner_size)
-            } else {
-                winit_window_attributes.with_min_inner_size(min_inner_size)
-            };
+        let winit_window_attributes = if let Some(max_inner_size) = max_inner_size {
+            winit_window_attributes
+                .with_min_inner_size(min_inner_size)
+                .with_max_inner_size(max_inner_size)
+        } else {
+            winit_window_attributes.with_min_inner_size(min_inner_size)
+        };
 
         #[expect(clippy::allow_attributes, reason = "`unused_mut` is not always linted")]
         #[allow(
@@ -275,7 +404,19 @@ impl WinitWindows {
             winit_window_attributes = winit_window_attributes.with_append(true);
         }
 
+        if let Some(attributes_hook) = attributes_hook {
+            attributes_hook.apply(entity, window, &mut winit_window_attributes);
+        }
+
         let winit_window = event_loop.create_window(winit_window_attributes).unwrap();
+        #[cfg(target_os = "macos")]
+        if simple_fullscreen {
+            use winit::platform::macos::WindowExtMacOS;
+            winit_window.set_simple_fullscreen(true);
+        }
+        if window.visible && window.visible_on_first_frame {
+            self.hidden_until_first_frame.insert(entity);
+        }
         let name = window.title.clone();
         prepare_accessibility_for_window(
             &winit_window,
@@ -286,12 +427,13 @@ impl WinitWindows {
             handlers,
         );
 
-        // Do not set the grab mode on window creation if it's none. It can fail on mobile.
-        if window.cursor_options.grab_mode != CursorGrabMode::None {
-            let _ = attempt_grab(&winit_window, window.cursor_options.grab_mode);
+        // Show the window now that its AccessKit adapter is initialized. It keeps the active
+        // state it was created with, so it doesn't take focus unless it should.
+        if visible && !visible_on_creation {
+            winit_window.set_visible(true);
         }
 
-        winit_window.set_cursor_visible(window.cursor_options.visible);
+        winit_window.set_cursor_visible(window.cursor_options.is_cursor_shown());
 
         // Do not set the cursor hittest on window creation if it's false, as it will always fail on
         // some platforms and log an unfixable warning.
@@ -304,8 +446,41 @@ impl WinitWindows {
             }
         }
 
+        if let Some(position) = window.initial_cursor_position {
+            let scale_factor = window
+                .resolution
+                .scale_factor_override()
+                .map_or(winit_window.scale_factor(), f64::from);
+            let position =
+                LogicalPosition::new(position.x, position.y).to_physical::<f64>(scale_factor);
+            if let Err(err) = winit_window.set_cursor_position(position) {
+                warn!(
+                    "Could not set initial cursor position for window {}: {}",
+                    window.title, err
+                );
+            }
+        }
+
+        if let Some(shape_mask) = &window.shape_mask {
+            self.hittest_masks
+                .insert(entity, HittestMask::new(shape_mask.polygons.clone()));
+        }
+
         self.entity_to_winit.insert(entity, winit_window.id());
+        self.titles.insert(entity, window.title.clone());
         self.winit_to_entity.insert(winit_window.id(), entity);
+        self.raise_in_z_order(entity);
+        self.record_input(entity, Instant::now());
+        self.resize_constraints
+            .insert(entity, window.resize_constraints);
+        let inner_size = winit_window.inner_size();
+        self.surface_sizes.insert(
+            entity,
+            (
+                UVec2::new(inner_size.width, inner_size.height),
+                window.surface_size(),
+            ),
+        );
 
         self.windows
             .entry(winit_window.id())
@@ -320,187 +495,3514 @@ impl WinitWindows {
             .and_then(|winit_id| self.windows.get(winit_id))
     }
 
-    /// Get the entity associated with the winit window id.
+    /// Runs `f` with the `winit` window associated with the entity, e.g. to call a `winit` API
+    /// that Bevy doesn't wrap yet.
     ///
-    /// This is mostly just an intermediary step between us and winit.
-    pub fn get_window_entity(&self, winit_id: WindowId) -> Option<Entity> {
-        self.winit_to_entity.get(&winit_id).cloned()
+    /// Since [`WinitWindows`] is a non-send resource, this can only run on the main thread, as
+    /// required by many `winit` window functions. Returns `None` if the entity has no window.
+    pub fn with_window<R>(&self, entity: Entity, f: impl FnOnce(&WinitWindow) -> R) -> Option<R> {
+        self.get_window(entity).map(|window| f(window))
     }
 
-    /// Remove a window from winit.
+    /// Notifies the windowing system that a frame is about to be presented to the window
+    /// associated with the entity.
     ///
-    /// This should mostly just be called when the window is closing.
-    pub fn remove_window(&mut self, entity: Entity) -> Option<WindowWrapper<WinitWindow>> {
-        let winit_id = self.entity_to_winit.remove(&entity)?;
-        self.winit_to_entity.remove(&winit_id);
-        self.windows.remove(&winit_id)
+    /// This helps compositors pace frames accurately, and is a cheap no-op on platforms that
+    /// don't need it. Windows created by Bevy already call this right before `bevy_render`
+    /// presents a frame to them, see [`WindowPresentation::set_pre_present_hook`], so this is
+    /// meant for custom renderers. Does nothing if the entity has no window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Schedules a frame callback to throttle redraws.
+    /// - **Android / iOS / X11 / Web / Windows / macOS:** No-op.
+    pub fn pre_present_notify(&self, entity: Entity) {
+        if let Some(window) = self.get_window(entity) {
+            window.pre_present_notify();
+        }
     }
-}
 
-/// Gets the "best" video mode which fits the given dimensions.
-///
-/// The heuristic for "best" prioritizes width, height, and refresh rate in that order.
-pub fn get_fitting_videomode(monitor: &MonitorHandle, width: u32, height: u32) -> VideoModeHandle {
-    monitor
-        .video_modes()
-        .max_by_key(|x| {
-            (
-                x.size().width.abs_diff(width),
-                x.size().height.abs_diff(height),
-                x.refresh_rate_millihertz(),
-            )
-        })
-        .unwrap()
-}
+    /// Returns the monitor of the currently focused window, if any.
+    fn focused_window_monitor(&self) -> Option<MonitorHandle> {
+        self.windows
+            .values()
+            .find(|window| window.has_focus())
+            .and_then(|window| window.current_monitor())
+    }
 
-/// Gets the "best" video-mode handle from a monitor.
-///
-/// The heuristic for "best" prioritizes width, height, and refresh rate in that order.
-pub fn get_best_videomode(monitor: &MonitorHandle) -> VideoModeHandle {
-    monitor
-        .video_modes()
-        .max_by_key(|x| (x.size(), x.refresh_rate_millihertz()))
-        .unwrap()
-}
+    /// Records the physical size and the rendering surface size of the window associated with
+    /// the entity, see [`Window::surface_size`], and returns whether either differs from the
+    /// previously recorded one.
+    pub(crate) fn update_surface_size(
+        &mut self,
+        entity: Entity,
+        physical_size: UVec2,
+        surface_size: UVec2,
+    ) -> bool {
+        let sizes = (physical_size, surface_size);
+        self.surface_sizes.insert(entity, sizes) != Some(sizes)
+    }
 
-pub(crate) fn attempt_grab(
-    winit_window: &WinitWindow,
-    grab_mode: CursorGrabMode,
-) -> Result<(), ExternalError> {
-    let grab_result = match grab_mode {
-        CursorGrabMode::None => winit_window.set_cursor_grab(WinitCursorGrabMode::None),
-        CursorGrabMode::Confined => winit_window
-            .set_cursor_grab(WinitCursorGrabMode::Confined)
-            .or_else(|_e| winit_window.set_cursor_grab(WinitCursorGrabMode::Locked)),
-        CursorGrabMode::Locked => winit_window
-            .set_cursor_grab(WinitCursorGrabMode::Locked)
-            .or_else(|_e| winit_window.set_cursor_grab(WinitCursorGrabMode::Confined)),
-    };
+    /// Requests a redraw of every window whose redraw interval has elapsed since its last redraw
+    /// request and that isn't in the middle of a burst of resizes, using the redraw interval and
+    /// resize redraw delay returned by `redraw_settings`.
+    ///
+    /// If the app hasn't requested a redraw, only windows that were resized are considered, so
+    /// that they are redrawn once their resizes have settled.
+    ///
+    /// Returns the earliest time at which a deferred window is due, if any.
+    pub(crate) fn request_redraws(
+        &mut self,
+        now: Instant,
+        redraw_requested: bool,
+        redraw_settings: impl Fn(Entity) -> (Option<Duration>, Option<Duration>),
+    ) -> Option<Instant> {
+        let mut next_redraw: Option<Instant> = None;
+        let entities: Vec<Entity> = if redraw_requested {
+            self.winit_to_entity.values().copied().collect()
+        } else {
+            self.last_resizes.keys().copied().collect()
+        };
+        for entity in entities {
+            let (interval, resize_delay) = redraw_settings(entity);
+            match self.poll_redraw(entity, now, interval, resize_delay) {
+                RedrawPoll::Now => {
+                    if let Some(window) = self.get_window(entity) {
+                        window.request_redraw();
+                    }
+                }
+                RedrawPoll::Deferred(due) => {
+                    next_redraw = Some(next_redraw.map_or(due, |next| next.min(due)));
+                }
+                RedrawPoll::Never => {}
+            }
+        }
+        next_redraw
+    }
 
-    if let Err(err) = grab_result {
-        let err_desc = match grab_mode {
-            CursorGrabMode::Confined | CursorGrabMode::Locked => "grab",
-            CursorGrabMode::None => "ungrab",
+    /// Asks the window associated with the entity to redraw, e.g. when an editor running only on
+    /// demand has marked the window as dirty.
+    ///
+    /// The resulting redraw event counts as a window event, so it wakes the app and runs an update
+    /// in [`UpdateMode::Continuous`](crate::UpdateMode::Continuous) and in
+    /// [`UpdateMode::Reactive`](crate::UpdateMode::Reactive) modes reacting to window events,
+    /// which includes [`UpdateMode::reactive_low_power`](crate::UpdateMode::reactive_low_power).
+    /// Unlike sending a [`RequestRedraw`](bevy_window::RequestRedraw) event, this only targets
+    /// the given window and isn't throttled by its [`Window::redraw_interval`]. Multiple requests
+    /// before the next redraw result in a single redraw. Returns `false` if the entity has no
+    /// window.
+    pub fn request_redraw(&self, entity: Entity) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
         };
+        window.request_redraw();
+        true
+    }
 
-        tracing::error!("Unable to {} cursor: {}", err_desc, err);
-        Err(err)
-    } else {
-        Ok(())
+    /// Records that the window associated with the entity was resized, deferring its redraw
+    /// until the resize redraw delay has elapsed, see [`Window::resize_redraw_delay`].
+    pub(crate) fn record_resize(&mut self, entity: Entity, now: Instant) {
+        self.last_resizes.insert(entity, now);
     }
-}
 
-/// Compute the physical window position for a given [`WindowPosition`].
-// Ideally we could generify this across window backends, but we only really have winit atm
-// so whatever.
-pub fn winit_window_position(
-    position: &WindowPosition,
-    resolution: &WindowResolution,
-    monitors: &WinitMonitors,
-    primary_monitor: Option<MonitorHandle>,
-    current_monitor: Option<MonitorHandle>,
-) -> Option<PhysicalPosition<i32>> {
-    match position {
-        WindowPosition::Automatic => {
-            // Window manager will handle position
-            None
+    /// Records that the window associated with the entity received user input at `now`.
+    pub(crate) fn record_input(&mut self, entity: Entity, now: Instant) {
+        self.last_inputs.insert(entity, now);
+    }
+
+    /// Returns how long ago the window associated with the entity last received keyboard,
+    /// mouse or touch input, or was created if it hasn't received any, e.g. to dim the UI of an
+    /// idle window.
+    ///
+    /// Returns `None` if there is no window associated with the entity.
+    pub fn idle_duration(&self, entity: Entity) -> Option<Duration> {
+        self.idle_duration_at(entity, Instant::now())
+    }
+
+    fn idle_duration_at(&self, entity: Entity, now: Instant) -> Option<Duration> {
+        self.last_inputs
+            .get(&entity)
+            .map(|last| now.saturating_duration_since(*last))
+    }
+
+    /// Records that the window associated with the entity was resized or moved at `now`.
+    ///
+    /// Resizing from the top or left edge also moves the window, so a move doesn't end an
+    /// ongoing resize.
+    pub(crate) fn record_interaction(
+        &mut self,
+        entity: Entity,
+        state: InteractionState,
+        now: Instant,
+    ) {
+        let state = match self.interaction_state_at(entity, now) {
+            InteractionState::Resizing => InteractionState::Resizing,
+            _ => state,
+        };
+        self.interactions.insert(entity, (state, now));
+    }
+
+    /// Returns whether the user is currently resizing or moving the window associated with the
+    /// entity, e.g. to simplify rendering while the window frame is being dragged.
+    ///
+    /// `winit` doesn't report when the user starts or stops dragging the window frame, so this
+    /// is inferred from the event stream: the window counts as resized or moved until no
+    /// resize or move has been reported for [`INTERACTION_SETTLE_TIME`]. Resizes and moves by
+    /// the app or the window manager, e.g. when maximizing, are reported the same way.
+    /// Returns [`InteractionState::Idle`] if the entity has no window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** Dragging the window frame runs a modal loop, during which the app only
+    ///   updates in response to the resize and move events. If the user holds the frame still
+    ///   for longer than [`INTERACTION_SETTLE_TIME`], the window counts as idle until it moves
+    ///   again.
+    /// - **Wayland:** Windows don't report their position, so moves aren't detected.
+    pub fn interaction_state(&self, entity: Entity) -> InteractionState {
+        self.interaction_state_at(entity, Instant::now())
+    }
+
+    fn interaction_state_at(&self, entity: Entity, now: Instant) -> InteractionState {
+        match self.interactions.get(&entity) {
+            Some((state, last))
+                if now.saturating_duration_since(*last) < INTERACTION_SETTLE_TIME =>
+            {
+                *state
+            }
+            _ => InteractionState::Idle,
         }
-        WindowPosition::Centered(monitor_selection) => {
-            let maybe_monitor = select_monitor(
-                monitors,
-                primary_monitor,
-                current_monitor,
-                monitor_selection,
-            );
+    }
 
-            if let Some(monitor) = maybe_monitor {
-                let screen_size = monitor.size();
-
-                let scale_factor = match resolution.scale_factor_override() {
-                    Some(scale_factor_override) => scale_factor_override as f64,
-                    // We use the monitors scale factor here since `WindowResolution.scale_factor` is
-                    // not yet populated when windows are created during plugin setup.
-                    None => monitor.scale_factor(),
-                };
-
-                // Logical to physical window size
-                let (width, height): (u32, u32) =
-                    LogicalSize::new(resolution.width(), resolution.height())
-                        .to_physical::<u32>(scale_factor)
-                        .into();
-
-                let position = PhysicalPosition {
-                    x: screen_size.width.saturating_sub(width) as f64 / 2.
-                        + monitor.position().x as f64,
-                    y: screen_size.height.saturating_sub(height) as f64 / 2.
-                        + monitor.position().y as f64,
-                };
-
-                Some(position.cast::<i32>())
-            } else {
-                warn!("Couldn't get monitor selected with: {monitor_selection:?}");
-                None
+    /// Returns whether the window associated with the entity is in a burst of resizes whose
+    /// redraw hasn't been requested yet.
+    pub(crate) fn is_resizing(&self, entity: Entity) -> bool {
+        self.last_resizes.contains_key(&entity)
+    }
+
+    /// Decides whether the window associated with the entity should be redrawn at `now`,
+    /// recording the redraw request if so.
+    fn poll_redraw(
+        &mut self,
+        entity: Entity,
+        now: Instant,
+        interval: Option<Duration>,
+        resize_delay: Option<Duration>,
+    ) -> RedrawPoll {
+        if let Some(last_resize) = self.last_resizes.get(&entity) {
+            match resize_delay.and_then(|delay| last_resize.checked_add(delay)) {
+                Some(due) if now < due => return RedrawPoll::Deferred(due),
+                _ => {
+                    self.last_resizes.remove(&entity);
+                }
             }
         }
-        WindowPosition::At(position) => {
-            Some(PhysicalPosition::new(position[0] as f64, position[1] as f64).cast::<i32>())
+
+        let Some(interval) = interval else {
+            self.last_redraw_requests.remove(&entity);
+            return RedrawPoll::Now;
+        };
+        match self
+            .last_redraw_requests
+            .get(&entity)
+            .map(|last| last.checked_add(interval))
+        {
+            Some(Some(due)) if now < due => RedrawPoll::Deferred(due),
+            // The interval is too long to ever elapse.
+            Some(None) => RedrawPoll::Never,
+            _ => {
+                self.last_redraw_requests.insert(entity, now);
+                RedrawPoll::Now
+            }
         }
     }
-}
 
-/// Selects a monitor based on the given [`MonitorSelection`].
-pub fn select_monitor(
-    monitors: &WinitMonitors,
-    primary_monitor: Option<MonitorHandle>,
-    current_monitor: Option<MonitorHandle>,
-    monitor_selection: &MonitorSelection,
-) -> Option<MonitorHandle> {
-    use bevy_window::MonitorSelection::*;
+    /// Marks the window associated with the entity as ready, returning `true` the first time.
+    pub(crate) fn mark_ready(&mut self, entity: Entity) -> bool {
+        self.ready_windows.insert(entity)
+    }
 
-    match monitor_selection {
-        Current => {
-            if current_monitor.is_none() {
-                warn!("Can't select current monitor on window creation or cannot find current monitor!");
+    /// Returns whether the window associated with the entity has presented at least one frame.
+    ///
+    /// This becomes `true` after the update following the one in which the renderer presented
+    /// the frame, see [`WindowPresentation`].
+    pub fn has_presented_first_frame(&self, entity: Entity) -> bool {
+        self.presented_first_frame.contains(&entity)
+    }
+
+    /// Records which windows the renderer has presented a frame to, and shows those that were
+    /// kept hidden until their first frame.
+    ///
+    /// Called after each app update. Without a renderer reporting its presented frames, each
+    /// update counts as presenting a frame to every window.
+    pub(crate) fn show_windows_after_first_frame(
+        &mut self,
+        presentation: Option<&WindowPresentation>,
+    ) {
+        let presented: Vec<Entity> = match presentation {
+            Some(presentation) if presentation.has_renderer() => {
+                presentation.take_presented().into_iter().collect()
+            }
+            _ => self.entity_to_winit.keys().copied().collect(),
+        };
+
+        let presented: Vec<Entity> = presented
+            .into_iter()
+            .filter(|entity| self.entity_to_winit.contains_key(entity))
+            .collect();
+        self.presented_first_frame.extend(presented.iter().copied());
+
+        for entity in presented {
+            // A splash window whose main window already has content is about to be closed, so
+            // showing it would only make it flash.
+            if self
+                .splash_windows
+                .get(&entity)
+                .is_some_and(|main_window| self.presented_first_frame.contains(main_window))
+            {
+                continue;
+            }
+            if self.hidden_until_first_frame.remove(&entity) {
+                if let Some(window) = self.get_window(entity) {
+                    window.set_visible(true);
+                }
             }
-            current_monitor
         }
-        Primary => primary_monitor,
-        Index(n) => monitors.nth(*n),
-        Entity(entity) => monitors.find_entity(*entity),
     }
-}
 
-struct DisplayInfo {
-    window_physical_resolution: (u32, u32),
-    window_logical_resolution: (f32, f32),
-    monitor_name: Option<String>,
-    scale_factor: Option<f64>,
-    refresh_rate_millihertz: Option<u32>,
-}
+    /// Makes only the given regions of the window associated with the entity capture the cursor,
+    /// letting clicks anywhere else pass through to the windows beneath.
+    ///
+    /// Each region is a polygon in logical window coordinates, with the origin at the top-left
+    /// corner. `winit` can only toggle hit-testing for the whole window, so the cursor position
+    /// is checked against the mask every frame and hit-testing is enabled while it is inside one
+    /// of the regions.
+    ///
+    /// While the cursor passes through, the window no longer receives cursor events, so the
+    /// position is queried from the platform where possible and estimated from raw mouse
+    /// motion otherwise. To keep receiving that motion while another app is focused, the event
+    /// loop listens to device events regardless of focus while any window has a mask.
+    ///
+    /// The estimated position is approximate: raw mouse motion is unaccelerated device motion,
+    /// not the distance the cursor moved on screen, and is lost while no device events are
+    /// received. The estimate drifts with pointer acceleration, and a region may capture the
+    /// cursor late or not at all until the cursor enters the window again and reports its real
+    /// position.
+    ///
+    /// Passing no regions removes the mask and makes the whole window capture the cursor again.
+    /// Does nothing if the entity has no window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** The real cursor position is queried from the X server while the cursor passes
+    ///   through, so it doesn't drift. Requires the `x11` feature.
+    /// - **iOS / Android / Web / Wayland:** Unsupported, like [`CursorOptions::hit_test`](bevy_window::CursorOptions::hit_test).
+    pub fn set_hittest_mask(&mut self, entity: Entity, regions: Vec<Vec<Vec2>>) {
+        if self.get_window(entity).is_none() {
+            return;
+        }
+        if regions.is_empty() {
+            if self.hittest_masks.remove(&entity).is_some() {
+                if let Some(window) = self.get_window(entity) {
+                    let _ = window.set_cursor_hittest(true);
+                }
+            }
+            return;
+        }
+        self.hittest_masks
+            .entry(entity)
+            .or_insert_with(|| HittestMask::new(Vec::new()))
+            .regions = regions;
+    }
 
-impl core::fmt::Display for DisplayInfo {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Display information:")?;
-        write!(
-            f,
-            "  Window physical resolution: {}x{}",
-            self.window_physical_resolution.0, self.window_physical_resolution.1
-        )?;
-        write!(
-            f,
-            "  Window logical resolution: {}x{}",
-            self.window_logical_resolution.0, self.window_logical_resolution.1
-        )?;
-        write!(
-            f,
-            "  Monitor name: {}",
-            self.monitor_name.as_deref().unwrap_or("")
-        )?;
-        write!(f, "  Scale factor: {}", self.scale_factor.unwrap_or(0.))?;
-        let millihertz = self.refresh_rate_millihertz.unwrap_or(0);
-        let hertz = millihertz / 1000;
-        let extra_millihertz = millihertz % 1000;
-        write!(f, "  Refresh rate (Hz): {}.{:03}", hertz, extra_millihertz)?;
-        Ok(())
+    /// Makes only the given regions of the window associated with the entity accept input,
+    /// e.g. for overlays that let input pass through everywhere else.
+    ///
+    /// This is a thin wrapper over [`set_hittest_mask`](Self::set_hittest_mask), and only
+    /// affects the cursor: clicks outside the regions pass through to the windows beneath, but
+    /// keyboard input doesn't. The window can still take keyboard focus, e.g. from the task bar
+    /// or with Alt-Tab, and then receives key presses anywhere. `winit` exposes neither
+    /// Wayland's `wl_surface.set_input_region` nor the X11 shape extension, which would also
+    /// stop the rest of the window from taking focus. Passing no regions removes the mask.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - Same as [`set_hittest_mask`](Self::set_hittest_mask).
+    pub fn set_input_region(&mut self, entity: Entity, regions: Vec<Vec<Vec2>>) {
+        self.set_hittest_mask(entity, regions);
+    }
+
+    /// Moves the estimated cursor position of windows that currently let the cursor pass
+    /// through, by a raw mouse motion `delta`.
+    ///
+    /// `winit` reports the delta as raw, unaccelerated device motion in unspecified units, so
+    /// treating it as physical pixels only approximates where the cursor went, see
+    /// [`WinitWindows::set_hittest_mask`].
+    pub(crate) fn accumulate_hittest_motion(&mut self, delta: Vec2) {
+        for (entity, mask) in self.hittest_masks.iter_mut() {
+            if mask.hittest {
+                continue;
+            }
+            let (Some(position), Some(window)) = (
+                mask.cursor_position.as_mut(),
+                self.entity_to_winit
+                    .get(entity)
+                    .and_then(|id| self.windows.get(id)),
+            ) else {
+                continue;
+            };
+            *position += delta / window.scale_factor() as f32;
+        }
+    }
+
+    /// Enables or disables cursor hit-testing of masked windows depending on whether the cursor
+    /// is inside their mask.
+    ///
+    /// `cursor_position` returns the logical cursor position reported by window events, which
+    /// replaces the estimated position whenever it changes. While the cursor passes through, the
+    /// position is queried from the platform instead where it can report one.
+    pub(crate) fn update_hittest_masks(
+        &mut self,
+        cursor_position: impl Fn(Entity) -> Option<Vec2>,
+    ) {
+        for (entity, mask) in self.hittest_masks.iter_mut() {
+            let Some(window) = self
+                .entity_to_winit
+                .get(entity)
+                .and_then(|id| self.windows.get(id))
+            else {
+                continue;
+            };
+            mask.report_cursor_position(cursor_position(*entity));
+            #[cfg(all(
+                feature = "x11",
+                any(
+                    target_os = "linux",
+                    target_os = "dragonfly",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd"
+                )
+            ))]
+            if !mask.hittest {
+                let position = x11_window_id(window)
+                    .and_then(|id| crate::x11::connection()?.cursor_position(id));
+                if let Some(position) = position {
+                    mask.cursor_position = Some(position.as_vec2() / window.scale_factor() as f32);
+                }
+            }
+            let Some(position) = mask.cursor_position else {
+                continue;
+            };
+            let hittest = mask
+                .regions
+                .iter()
+                .any(|region| polygon_contains(region, position));
+            if hittest != mask.hittest && window.set_cursor_hittest(hittest).is_ok() {
+                mask.hittest = hittest;
+            }
+        }
+    }
+
+    /// Get the raw window and display handles of the window associated with the entity.
+    ///
+    /// This is meant for external rendering or native overlay integrations. Returns `None` if
+    /// the entity has no window or if the handles are currently unavailable, e.g. on Android
+    /// while the app is suspended.
+    pub fn window_handles(&self, entity: Entity) -> Option<(RawWindowHandle, RawDisplayHandle)> {
+        let window = self.get_window(entity)?;
+        let window_handle = window.window_handle().ok()?.as_raw();
+        let display_handle = window.display_handle().ok()?.as_raw();
+        Some((window_handle, display_handle))
+    }
+
+    /// Get the entity associated with the winit window id.
+    ///
+    /// This is mostly just an intermediary step between us and winit.
+    pub fn get_window_entity(&self, winit_id: WindowId) -> Option<Entity> {
+        self.winit_to_entity.get(&winit_id).cloned()
+    }
+
+    /// Iterates over all winit windows along with the entity they are associated with.
+    ///
+    /// Windows that are not (or no longer) associated with an entity are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &WindowWrapper<WinitWindow>)> {
+        self.winit_to_entity
+            .iter()
+            .filter_map(|(winit_id, entity)| Some((*entity, self.windows.get(winit_id)?)))
+    }
+
+    /// Remove a window from winit.
+    ///
+    /// This should mostly just be called when the window is closing.
+    pub fn remove_window(&mut self, entity: Entity) -> Option<WindowWrapper<WinitWindow>> {
+        // The parent has to be unblocked before the modal window is destroyed, otherwise the
+        // platform could activate another app's window instead of the disabled parent.
+        self.unblock_modal_parent(entity);
+        self.modal_parents.retain(|_, parent| *parent != entity);
+        self.hittest_masks.remove(&entity);
+        self.hidden_until_first_frame.remove(&entity);
+        self.pending_scale_factors.remove(&entity);
+        self.cursor_positions.remove(&entity);
+        self.relative_mouse.remove(&entity);
+        self.pending_grabs.remove(&entity);
+        self.window_anchors
+            .retain(|dependent, anchor| *dependent != entity && *anchor != entity);
+        self.ready_windows.remove(&entity);
+        self.last_redraw_requests.remove(&entity);
+        self.presented_first_frame.remove(&entity);
+        self.splash_windows.remove(&entity);
+        self.titles.remove(&entity);
+        self.edited_documents.remove(&entity);
+        self.last_resizes.remove(&entity);
+        self.last_inputs.remove(&entity);
+        self.accessibility_names.remove(&entity);
+        self.renamed_windows.remove(&entity);
+        self.interactions.remove(&entity);
+        self.surface_sizes.remove(&entity);
+        self.z_order.retain(|window| *window != entity);
+        self.pending_closes.remove(&entity);
+        self.activations.remove(&entity);
+        self.resize_constraints.remove(&entity);
+        self.confirmed_closes.remove(&entity);
+        self.window_updates.remove(&entity);
+        self.held_resizes.remove(&entity);
+        let winit_id = self.entity_to_winit.remove(&entity)?;
+        self.winit_to_entity.remove(&winit_id);
+        self.windows.remove(&winit_id)
+    }
+
+    /// Stops the modal window associated with the entity from blocking its parent, unblocking
+    /// the parent unless another modal window still blocks it.
+    pub(crate) fn unblock_modal_parent(&mut self, modal: Entity) {
+        let Some(parent) = self.modal_parents.remove(&modal) else {
+            return;
+        };
+        if self
+            .modal_parents
+            .values()
+            .all(|blocked| *blocked != parent)
+        {
+            if let Some(parent_window) = self.get_window(parent) {
+                set_modal_parent_enabled(parent_window, true);
+            }
+        }
+    }
+
+    /// Replaces the window associated with the entity with a freshly created one, reusing the
+    /// same [`Window`] settings.
+    ///
+    /// The stale window is replaced, e.g. after its surface was lost on Android or when the GPU
+    /// device was reset, and dropped one frame later on the main thread like a closed window.
+    /// Its accessibility adapter is dropped right away. State set through [`WinitWindows`],
+    /// such as the title from [`WinitWindows::set_title`], the document edited mark, the
+    /// position in the z-order and a pending close, carries over to the new window. Callers
+    /// are responsible for inserting a new [`RawHandleWrapper`](bevy_window::RawHandleWrapper)
+    /// on the entity, since the previous handles no longer point to a valid window.
+    pub fn recreate_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        entity: Entity,
+        window: &Window,
+        adapters: &mut AccessKitAdapters,
+        handlers: &mut WinitActionRequestHandlers,
+        accessibility_requested: &AccessibilityRequested,
+        monitors: &WinitMonitors,
+        attributes_hook: Option<&WindowAttributesHook>,
+    ) -> &WindowWrapper<WinitWindow> {
+        let title = self.titles.get(&entity).cloned();
+        let accessibility_name = self.accessibility_names.get(&entity).cloned();
+        let edited = self.edited_documents.contains(&entity);
+        let z_index = self.z_order.iter().position(|window| *window == entity);
+        let close_pending = self.pending_closes.contains(&entity);
+
+        if let Some(stale) = self.remove_window(entity) {
+            self.recreated_windows.push(stale);
+        }
+        adapters.remove(&entity);
+        handlers.remove(&entity);
+
+        self.create_window(
+            event_loop,
+            entity,
+            window,
+            adapters,
+            handlers,
+            accessibility_requested,
+            monitors,
+            attributes_hook,
+        );
+
+        if let Some(title) = title {
+            self.titles.insert(entity, title);
+        }
+        if let Some(name) = accessibility_name {
+            self.accessibility_names.insert(entity, name);
+            self.renamed_windows.insert(entity);
+        }
+        if let Some(index) = z_index {
+            self.z_order.retain(|window| *window != entity);
+            let index = index.min(self.z_order.len());
+            self.z_order.insert(index, entity);
+        }
+        if close_pending {
+            self.pending_closes.insert(entity);
+        }
+        if edited {
+            self.set_document_edited(entity, true);
+        } else if let Some(title) = self.titles.get(&entity) {
+            let displayed = self.displayed_title(entity, title).into_owned();
+            if let Some(winit_window) = self.get_window(entity) {
+                winit_window.set_title(&displayed);
+            }
+        }
+
+        self.get_window(entity)
+            .expect("the window was just created")
+    }
+
+    /// Returns whether the window associated with the entity is minimized.
+    ///
+    /// Returns `None` if the entity has no window, or if the platform can't determine the
+    /// minimized state (e.g. **`Wayland`**).
+    pub fn is_minimized(&self, entity: Entity) -> Option<bool> {
+        self.get_window(entity)?.is_minimized()
+    }
+
+    /// Returns whether the window associated with the entity is maximized.
+    ///
+    /// Returns `None` if the entity has no window.
+    pub fn is_maximized(&self, entity: Entity) -> Option<bool> {
+        self.get_window(entity).map(|window| window.is_maximized())
+    }
+
+    /// Returns whether a close of the window associated with the entity was requested and
+    /// neither canceled nor confirmed yet.
+    pub fn is_close_pending(&self, entity: Entity) -> bool {
+        self.pending_closes.contains(&entity)
+    }
+
+    /// Cancels the pending close of the window associated with the entity, e.g. when the user
+    /// dismisses a "Quit without saving?" dialog shown for a
+    /// [`WindowCloseRequested`](bevy_window::WindowCloseRequested) event.
+    ///
+    /// The window keeps running with its state intact. Windows without
+    /// [`PreventClose`](bevy_window::PreventClose) are closed on request regardless. Returns
+    /// whether a close was pending.
+    pub fn cancel_close(&mut self, entity: Entity) -> bool {
+        self.pending_closes.remove(&entity)
+    }
+
+    /// Confirms the pending close of the window associated with the entity, e.g. once the user
+    /// saved from a "Quit without saving?" dialog.
+    ///
+    /// The window is marked as [`ClosingWindow`](bevy_window::ClosingWindow) in the next update
+    /// and despawned in the update after, like any other closed window. Returns whether a close was
+    /// pending, otherwise nothing happens.
+    pub fn confirm_close(&mut self, entity: Entity) -> bool {
+        if !self.pending_closes.remove(&entity) {
+            return false;
+        }
+        self.confirmed_closes.insert(entity);
+        true
+    }
+
+    /// Returns whether the window associated with the entity is active and why, as last sent
+    /// in a [`WindowActivationChanged`](bevy_window::WindowActivationChanged) event.
+    ///
+    /// Returns `None` if the window's focus hasn't changed since it was created.
+    pub fn activation(&self, entity: Entity) -> Option<(bool, ActivationReason)> {
+        self.activations.get(&entity).copied()
+    }
+
+    /// Returns whether the window associated with the entity currently has input focus.
+    ///
+    /// Unlike [`Window::focused`], which is updated from [`WindowFocused`](bevy_window::WindowFocused)
+    /// events, this queries the backend directly. Returns `None` if the entity has no window.
+    pub fn has_focus(&self, entity: Entity) -> Option<bool> {
+        self.get_window(entity).map(|window| window.has_focus())
+    }
+
+    /// Returns the last known position of the cursor inside the window associated with the
+    /// entity, in logical pixels relative to the top-left corner of the window.
+    ///
+    /// This is kept up to date from cursor events, since `winit` can't query the cursor position
+    /// on demand. It matches [`Window::cursor_position`], but can be used where only
+    /// [`WinitWindows`] is available. Returns `None` if the cursor isn't inside the window or if
+    /// the entity has no window.
+    pub fn cursor_position(&self, entity: Entity) -> Option<Vec2> {
+        self.cursor_positions.get(&entity).copied()
+    }
+
+    /// Keeps the window associated with `entity` directly above the window associated with
+    /// `anchor`, e.g. a floating toolbar over its document, without making it
+    /// [`WindowLevel::AlwaysOnTop`] for every other window.
+    ///
+    /// `winit` only offers global window levels, so this is a best-effort emulation: whenever the
+    /// anchor gains focus, the dependent window is re-raised by briefly toggling its window level,
+    /// which doesn't take focus away from the anchor. Windows of other apps may still end up
+    /// between the two. Replaces any previous anchor of the window. Returns `false` if either
+    /// entity has no window or if both are the same window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Wayland:** Window levels are unsupported, so this has no effect.
+    pub fn set_window_above(&mut self, entity: Entity, anchor: Entity) -> bool {
+        if entity == anchor
+            || self.get_window(entity).is_none()
+            || self.get_window(anchor).is_none()
+        {
+            return false;
+        }
+        self.window_anchors.insert(entity, anchor);
+        true
+    }
+
+    /// Stops keeping the window associated with the entity above its anchor, see
+    /// [`WinitWindows::set_window_above`]. Returns the previous anchor, if any.
+    pub fn clear_window_above(&mut self, entity: Entity) -> Option<Entity> {
+        self.window_anchors.remove(&entity)
+    }
+
+    /// Enables or disables relative mouse mode for the window associated with the entity, e.g.
+    /// for mouselook in a first-person camera.
+    ///
+    /// When enabled, the cursor is hidden and [`CursorGrabMode::Locked`], falling back to
+    /// [`CursorGrabMode::Confined`] if locking is unsupported. While confined, the cursor is also
+    /// moved back to the center of the window every frame, so it never hits the window edges.
+    /// Read the relative movement from [`MouseMotion`](bevy_input::mouse::MouseMotion) events.
+    ///
+    /// Disabling releases the grab and shows the cursor again, regardless of the window's
+    /// [`CursorOptions`](bevy_window::CursorOptions). Returns whether the change took effect,
+    /// which is `false` if the entity has no window or if the cursor couldn't be grabbed.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Wayland:** Moving the cursor is unsupported, so a confined cursor
+    ///   isn't recentered.
+    pub fn enable_relative_mouse(&mut self, entity: Entity, enabled: bool) -> bool {
+        if !enabled {
+            let was_enabled = self.relative_mouse.remove(&entity).is_some();
+            let Some(winit_window) = self.get_window(entity) else {
+                return false;
+            };
+            if !was_enabled {
+                return true;
+            }
+            winit_window.set_cursor_visible(true);
+            return attempt_grab(winit_window, CursorGrabMode::None, None).is_ok();
+        }
+
+        let Some(winit_window) = self.get_window(entity) else {
+            return false;
+        };
+        let Ok(grab_mode) = attempt_grab(winit_window, CursorGrabMode::Locked, None) else {
+            return false;
+        };
+        winit_window.set_cursor_visible(false);
+        self.relative_mouse.insert(entity, grab_mode);
+        true
+    }
+
+    /// Returns whether relative mouse mode is enabled for the window associated with the entity,
+    /// see [`WinitWindows::enable_relative_mouse`].
+    pub fn is_relative_mouse_enabled(&self, entity: Entity) -> bool {
+        self.relative_mouse.contains_key(&entity)
+    }
+
+    /// Removes input focus from the window associated with the entity, e.g. to dismiss an IME
+    /// popup when a text field is closed.
+    ///
+    /// `winit` has no way to blur a window, so this is best-effort: focus is handed to another
+    /// visible, non-minimized window of the app instead. Returns whether focus was moved, which
+    /// is `false` if the entity has no window, if the window isn't focused, or if the app has no
+    /// other window to focus.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - Lowering the window behind windows of other apps isn't supported, as `winit` doesn't
+    ///   expose it on any platform.
+    /// - **Wayland / Web:** Focus can't be moved programmatically, so this has no effect.
+    pub fn blur(&self, entity: Entity) -> bool {
+        if !self
+            .get_window(entity)
+            .is_some_and(|window| window.has_focus())
+        {
+            return false;
+        }
+
+        let Some((_, other)) = self.iter().find(|(other_entity, other)| {
+            *other_entity != entity
+                && other.is_visible() != Some(false)
+                && other.is_minimized() != Some(true)
+        }) else {
+            return false;
+        };
+
+        other.focus_window();
+        true
+    }
+
+    /// Brings the window associated with the entity to the front without moving keyboard focus
+    /// to it, e.g. for tool windows in multi-window layouts that shouldn't take focus away from a
+    /// text field in another window.
+    ///
+    /// `level` should be the window's current [`Window::window_level`], which it is restored to.
+    /// `winit` has no way to raise a window without activating it, so this is best-effort: the
+    /// window is briefly made [`WindowLevel::AlwaysOnTop`], which window managers apply without
+    /// activating the window. Where window levels are unsupported, this falls back to
+    /// [`focus_window`](WinitWindow::focus_window), which does move focus.
+    ///
+    /// Returns whether the window was raised without being focused, which is `false` if the
+    /// entity has no window or if the fallback was used.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / Web / iOS / Android:** Always falls back to `focus_window`.
+    pub fn raise_without_focus(&mut self, entity: Entity, level: WindowLevel) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+
+        if !self.supports(WindowFeature::WindowLevel) {
+            window.focus_window();
+            return false;
+        }
+
+        window.set_window_level(convert_window_level(WindowLevel::AlwaysOnTop, false));
+        window.set_window_level(convert_window_level(level, false));
+        self.raise_in_z_order(entity);
+        true
+    }
+
+    /// Returns the app's window entities sorted from front to back, e.g. to hit-test clicks
+    /// across overlapping windows.
+    ///
+    /// `winit` doesn't expose the stacking order of windows, so it is approximated: windows are
+    /// moved to the front when they are created, when they gain focus and when they are raised
+    /// with [`WinitWindows::raise_without_focus`]. Among the app's own windows this is accurate
+    /// unless the user or another app reorders them without focusing them, and it says nothing
+    /// about windows of other apps in between.
+    pub fn z_ordered_entities(&self) -> Vec<Entity> {
+        self.z_order.clone()
+    }
+
+    /// Moves the window associated with the entity to the front of the approximate z-order.
+    pub(crate) fn raise_in_z_order(&mut self, entity: Entity) {
+        self.z_order.retain(|window| *window != entity);
+        self.z_order.insert(0, entity);
+    }
+
+    /// Returns the window entities that are currently on the selected monitor, e.g. to move
+    /// windows off a monitor that is about to be disconnected.
+    ///
+    /// Windows that report no current monitor are excluded. [`MonitorSelection::Current`] is
+    /// resolved for each window, so it matches every window that is on some monitor.
+    pub fn windows_on_monitor(
+        &self,
+        monitors: &WinitMonitors,
+        monitor_selection: &MonitorSelection,
+    ) -> Vec<Entity> {
+        self.entity_to_winit
+            .iter()
+            .filter_map(|(entity, id)| {
+                let window = self.windows.get(id)?;
+                let current_monitor = window.current_monitor()?;
+                let selected_monitor = select_monitor(
+                    monitors,
+                    window.primary_monitor(),
+                    Some(current_monitor.clone()),
+                    monitor_selection,
+                )?;
+                (selected_monitor == current_monitor).then_some(*entity)
+            })
+            .collect()
+    }
+
+    /// Returns the refresh rate, in millihertz, of the monitor the window associated with the
+    /// entity is currently on, e.g. for adaptive frame pacing.
+    ///
+    /// Unlike [`DisplayInfo::refresh_rate_millihertz`], which is captured when the window is
+    /// created, this follows the window across monitors. Returns `None` if the entity has no
+    /// window, or if the current monitor or its refresh rate is unknown.
+    pub fn current_refresh_rate_millihertz(&self, entity: Entity) -> Option<u32> {
+        self.get_window(entity)?
+            .current_monitor()?
+            .refresh_rate_millihertz()
+    }
+
+    /// Returns the fullscreen state the window associated with the entity actually got, e.g. to
+    /// show players whether the exclusive fullscreen they asked for was granted.
+    ///
+    /// The platform may silently fall back to borderless fullscreen when
+    /// [`WindowMode::Fullscreen`] is requested, which affects vsync and video mode switching.
+    /// Returns `None` if the entity has no window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Exclusive fullscreen is never granted.
+    /// - **Android / Orbital:** Always reports [`FullscreenState::Windowed`].
+    pub fn fullscreen_state(&self, entity: Entity) -> Option<FullscreenState> {
+        let state = match self.get_window(entity)?.fullscreen() {
+            Some(Fullscreen::Exclusive(_)) => FullscreenState::Exclusive,
+            Some(Fullscreen::Borderless(_)) => FullscreenState::Borderless,
+            None => FullscreenState::Windowed,
+        };
+        Some(state)
+    }
+
+    /// Returns the windowing backend the event loop was built with.
+    ///
+    /// This is `None` on platforms other than Linux, which only have a single backend.
+    pub fn backend(&self) -> Option<WinitBackend> {
+        self.backend
+    }
+
+    /// Returns whether the current platform supports the given window feature, e.g. to hide
+    /// settings toggles that would silently do nothing.
+    ///
+    /// On Linux, the answer depends on the windowing [`backend`](Self::backend) in use.
+    pub fn supports(&self, feature: WindowFeature) -> bool {
+        let windows = cfg!(target_os = "windows");
+        let macos = cfg!(target_os = "macos");
+        let ios = cfg!(target_os = "ios");
+        let web = cfg!(target_arch = "wasm32");
+        let x11 = self.backend == Some(WinitBackend::X11);
+        let wayland = self.backend == Some(WinitBackend::Wayland);
+
+        match feature {
+            WindowFeature::CursorLock => macos || wayland || web,
+            WindowFeature::CursorConfine => windows || x11 || wayland,
+            WindowFeature::CursorPosition | WindowFeature::WindowLevel => windows || macos || x11,
+            WindowFeature::ExclusiveFullscreen => windows || macos || ios || x11,
+            WindowFeature::WindowPosition => windows || macos || ios || x11 || web,
+            WindowFeature::WindowIcon => windows || x11,
+            WindowFeature::Ime => windows || macos || x11 || wayland,
+            WindowFeature::CustomCursor => {
+                cfg!(feature = "custom_cursor") && (windows || macos || x11 || wayland || web)
+            }
+            WindowFeature::VisibleOnAllWorkspaces => macos || (cfg!(feature = "x11") && x11),
+            WindowFeature::Badge => macos,
+        }
+    }
+
+    /// Moves and resizes the window associated with the entity to the geometry remembered for
+    /// its current monitor in `memory`, e.g. when it was moved to another monitor.
+    ///
+    /// On a monitor without remembered geometry, the window is centered at three quarters of the
+    /// monitor's size. Returns the physical outer position and inner size that were requested,
+    /// or `None` if the entity has no window or its monitor can't be determined.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / Android:** Windows can't be moved, so only the size is applied.
+    pub fn restore_for_current_monitor(
+        &self,
+        entity: Entity,
+        memory: &WindowMemory,
+        monitors: &WinitMonitors,
+    ) -> Option<(IVec2, UVec2)> {
+        let window = self.get_window(entity)?;
+        let monitor = window_current_monitor(monitors, window)?;
+        let monitor_position = monitor.position();
+        let monitor_size = monitor.size();
+        let (position, size) = memory.geometry_on(
+            monitor_identity(monitors, &monitor).as_ref(),
+            IVec2::new(monitor_position.x, monitor_position.y),
+            UVec2::new(monitor_size.width, monitor_size.height),
+        );
+        window.set_outer_position(PhysicalPosition::new(position.x, position.y));
+        let _ = window.request_inner_size(PhysicalSize::new(size.x, size.y));
+        Some((position, size))
+    }
+
+    /// Moves and resizes the window associated with the entity so that its outer rectangle
+    /// covers the work area of the selected monitor, while keeping it a normal window.
+    ///
+    /// Unlike [`Window::set_maximized`], which leaves the geometry and title bar behavior to the
+    /// OS, this requests exactly the work area, e.g. for a borderless window that should fill
+    /// the screen without going fullscreen. The window is taken out of fullscreen and the
+    /// maximized state first. Returns the physical outer position and inner size that were
+    /// requested, or `None` if the entity has no window or the monitor can't be resolved.
+    ///
+    /// Note that `winit` doesn't expose the work area of a monitor, so for now this falls back
+    /// to the full monitor on every platform. The window then covers the task bars and docks as
+    /// well, or is partly hidden behind them, depending on the OS.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland / Android:** Windows can't be moved, so only the size is applied.
+    pub fn maximize_to_work_area(
+        &self,
+        entity: Entity,
+        monitors: &WinitMonitors,
+        monitor_selection: &MonitorSelection,
+    ) -> Option<(IVec2, UVec2)> {
+        let window = self.get_window(entity)?;
+        let monitor = select_monitor(
+            monitors,
+            window.primary_monitor(),
+            window_current_monitor(monitors, window),
+            monitor_selection,
+        )?;
+        let (position, area_size) = work_area(&monitor);
+
+        if window.fullscreen().is_some() {
+            window.set_fullscreen(None);
+        }
+        if window.is_maximized() {
+            window.set_maximized(false);
+        }
+
+        // The inner size excludes the decorations, so subtract them to fit the outer rectangle.
+        let outer_size = window.outer_size();
+        let inner_size = window.inner_size();
+        let decorations = UVec2::new(
+            outer_size.width.saturating_sub(inner_size.width),
+            outer_size.height.saturating_sub(inner_size.height),
+        );
+        let size = area_size.saturating_sub(decorations);
+        window.set_outer_position(PhysicalPosition::new(position.x, position.y));
+        let _ = window.request_inner_size(PhysicalSize::new(size.x, size.y));
+        Some((position, size))
+    }
+
+    /// Returns the physical position and size of the outer rectangle of the window associated
+    /// with the entity, including the title bar and borders.
+    ///
+    /// Returns `None` if the entity has no window or if the outer position can't be determined,
+    /// in which case callers can fall back to the inner size in [`Window::resolution`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Android / Wayland:** The outer position is unavailable, so this always returns `None`.
+    pub fn outer_rect(&self, entity: Entity) -> Option<(IVec2, UVec2)> {
+        let window = self.get_window(entity)?;
+        let position = window.outer_position().ok()?;
+        let size = window.outer_size();
+        Some((
+            IVec2::new(position.x, position.y),
+            UVec2::new(size.width, size.height),
+        ))
+    }
+
+    /// Returns the physical and logical inner size of the window associated with the entity,
+    /// together with the scale factor used to convert between them.
+    ///
+    /// All values are read from the same `winit` window at once, so they stay consistent with
+    /// each other even if a resize or scale factor change happens between separate reads of
+    /// [`Window::resolution`]. Returns `None` if the entity has no window.
+    pub fn inner_size(&self, entity: Entity) -> Option<WindowSizes> {
+        let window = self.get_window(entity)?;
+        let scale_factor = window.scale_factor();
+        let physical = window.inner_size();
+        let logical = physical.to_logical::<f32>(scale_factor);
+        Some(WindowSizes {
+            physical: UVec2::new(physical.width, physical.height),
+            logical: Vec2::new(logical.width, logical.height),
+            scale_factor,
+        })
+    }
+
+    /// Converts a logical position to physical pixels using the current scale factor of the
+    /// window associated with the entity, rounding to the nearest pixel.
+    ///
+    /// This uses the scale factor of the `winit` window itself, which can be more recent than
+    /// [`Window::resolution`] while a scale factor change is being processed. Returns `None` if
+    /// the entity has no window.
+    pub fn to_physical(&self, entity: Entity, logical: Vec2) -> Option<IVec2> {
+        let window = self.get_window(entity)?;
+        let physical =
+            LogicalPosition::new(logical.x, logical.y).to_physical::<i32>(window.scale_factor());
+        Some(IVec2::new(physical.x, physical.y))
+    }
+
+    /// Converts a physical position to logical pixels using the current scale factor of the
+    /// window associated with the entity.
+    ///
+    /// This is the inverse of [`WinitWindows::to_physical`]. Returns `None` if the entity has no
+    /// window.
+    pub fn to_logical(&self, entity: Entity, physical: IVec2) -> Option<Vec2> {
+        let window = self.get_window(entity)?;
+        let logical =
+            PhysicalPosition::new(physical.x, physical.y).to_logical::<f32>(window.scale_factor());
+        Some(Vec2::new(logical.x, logical.y))
+    }
+
+    /// Requests a new inner size, in logical pixels, for the window associated with the entity,
+    /// e.g. to fit the window to the size of its UI content.
+    ///
+    /// Returns the new physical inner size if the platform applied it immediately. Returns `None`
+    /// if the entity has no window, or if the resize happens asynchronously. In the asynchronous
+    /// case, the size has not taken effect yet: wait for the next
+    /// [`WindowResized`](bevy_window::WindowResized) event instead of assuming the new size.
+    /// [`Window::resolution`] is updated from that event in either case.
+    ///
+    /// On platforms where the windowing system disallows resizing, the current inner size is
+    /// returned and the request is ignored. The request may also un-maximize the window.
+    pub fn request_inner_size(&self, entity: Entity, size: Vec2) -> Option<UVec2> {
+        let window = self.get_window(entity)?;
+        window
+            .request_inner_size(LogicalSize::new(size.x, size.y))
+            .map(|size| UVec2::new(size.width, size.height))
+    }
+
+    /// Requests a new inner size, in physical pixels, for the window associated with the entity,
+    /// regardless of its scale factor, e.g. for pixel-exact capture or recording windows.
+    ///
+    /// Like [`WinitWindows::request_inner_size`], this returns the new physical inner size if the
+    /// platform applied it immediately, and `None` if the entity has no window or if the resize
+    /// happens asynchronously. The compositor may not honor the exact size (e.g. when tiling, or
+    /// to fit the size constraints or monitor), so treat the returned size, or the size of the
+    /// next [`WindowResized`](bevy_window::WindowResized) event, as authoritative rather than the
+    /// requested one.
+    pub fn set_physical_inner_size(&self, entity: Entity, size: UVec2) -> Option<UVec2> {
+        let window = self.get_window(entity)?;
+        window
+            .request_inner_size(PhysicalSize::new(size.x, size.y))
+            .map(|size| UVec2::new(size.width, size.height))
+    }
+
+    /// Sets the cursor of the window associated with the entity to a standard system cursor,
+    /// e.g. a resize arrow while hovering the edge of a resizable panel.
+    ///
+    /// Prefer inserting a [`CursorIcon`](crate::CursorIcon) on the window entity from within
+    /// systems, which replaces the cursor set here whenever it changes. Does nothing if the
+    /// entity has no window.
+    pub fn set_cursor_icon(&self, entity: Entity, icon: SystemCursorIcon) {
+        if let Some(window) = self.get_window(entity) {
+            window.set_cursor(convert_system_cursor_icon(icon));
+        }
+    }
+
+    /// Enables or disables the window control buttons of the window associated with the entity,
+    /// e.g. to disable the close button while a modal dialog is open.
+    ///
+    /// Prefer setting [`Window::enabled_buttons`] from within systems, this is meant for code that
+    /// already has access to [`WinitWindows`]. Does nothing if the entity has no window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Disabling the maximize button also disables the green zoom button.
+    /// - **Wayland / X11 / Web / iOS / Android:** Unsupported, nothing is changed.
+    pub fn set_enabled_buttons(&self, entity: Entity, enabled_buttons: EnabledButtons) {
+        if let Some(window) = self.get_window(entity) {
+            window.set_enabled_buttons(convert_enabled_buttons(enabled_buttons));
+        }
+    }
+
+    /// Minimizes or un-minimizes the window associated with the entity.
+    ///
+    /// Prefer [`Window::set_minimized`] from within systems, this is meant for code that already
+    /// has access to [`WinitWindows`]. Does nothing if the entity has no window.
+    pub fn set_minimized(&self, entity: Entity, minimized: bool) {
+        if let Some(window) = self.get_window(entity) {
+            window.set_minimized(minimized);
+        }
+    }
+
+    /// Maximizes or un-maximizes the window associated with the entity.
+    ///
+    /// Prefer [`Window::set_maximized`] from within systems, this is meant for code that already
+    /// has access to [`WinitWindows`]. Does nothing if the entity has no window.
+    pub fn set_maximized(&self, entity: Entity, maximized: bool) {
+        if let Some(window) = self.get_window(entity) {
+            window.set_maximized(maximized);
+        }
+    }
+
+    /// Changes the title of the window associated with the entity, keeping the name of its
+    /// `AccessKit` window node in sync so assistive technology announces the current title.
+    ///
+    /// The name is updated with the next accessibility update, and is used instead of
+    /// [`Window::title`] until that changes. Prefer setting [`Window::title`] from within
+    /// systems, this is meant for code that already has access to [`WinitWindows`]. Does
+    /// nothing if the entity has no window.
+    pub fn set_title(&mut self, entity: Entity, title: &str) {
+        let Some(window) = self.get_window(entity) else {
+            return;
+        };
+        window.set_title(&self.displayed_title(entity, title));
+        self.titles.insert(entity, title.into());
+        self.accessibility_names.insert(entity, title.into());
+        self.renamed_windows.insert(entity);
+    }
+
+    /// Returns the native title to show for `title`, including the mark added by
+    /// [`WinitWindows::set_document_edited`] on platforms without a native one.
+    pub(crate) fn displayed_title<'a>(&self, entity: Entity, title: &'a str) -> Cow<'a, str> {
+        if cfg!(not(target_os = "macos")) && self.edited_documents.contains(&entity) {
+            Cow::Owned(format!("{title} *"))
+        } else {
+            Cow::Borrowed(title)
+        }
+    }
+
+    /// Returns the name of the `AccessKit` window node of the window associated with the entity
+    /// if it was set with [`WinitWindows::set_title`], otherwise [`Window::title`] is used.
+    pub(crate) fn accessibility_name(&self, entity: Entity) -> Option<&str> {
+        self.accessibility_names.get(&entity).map(String::as_str)
+    }
+
+    /// Marks the window associated with the entity as having unsaved changes, or clears the mark.
+    ///
+    /// Does nothing if the entity has no window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Shows a dot in the close button.
+    /// - **Others:** Appends ` *` to the title, following the common convention. The mark is kept
+    ///   when [`Window::title`] changes, and isn't part of [`Window::title`] itself.
+    pub fn set_document_edited(&mut self, entity: Entity, edited: bool) {
+        if self.get_window(entity).is_none() {
+            return;
+        }
+        if edited {
+            self.edited_documents.insert(entity);
+        } else {
+            self.edited_documents.remove(&entity);
+        }
+        let Some(window) = self.get_window(entity) else {
+            return;
+        };
+
+        #[cfg(target_os = "macos")]
+        {
+            use winit::platform::macos::WindowExtMacOS;
+            window.set_document_edited(edited);
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        if let Some(title) = self.titles.get(&entity) {
+            window.set_title(&self.displayed_title(entity, title));
+        }
+    }
+
+    /// Changes the theme of the window associated with the entity.
+    ///
+    /// `None` follows the system theme, matching [`Window::window_theme`]. Changes of the system
+    /// theme are reported through [`WindowThemeChanged`](bevy_window::WindowThemeChanged).
+    ///
+    /// Prefer setting [`Window::window_theme`] from within systems, this is meant for code that
+    /// already has access to [`WinitWindows`]. Does nothing if the entity has no window.
+    pub fn set_theme(&self, entity: Entity, theme: Option<WindowTheme>) {
+        if let Some(window) = self.get_window(entity) {
+            window.set_theme(theme.map(convert_window_theme));
+        }
+    }
+
+    /// Changes the minimum and maximum inner size of the window associated with the entity.
+    ///
+    /// An infinite maximum width or height clears the maximum size. The constraints are written
+    /// back to [`Window::resize_constraints`] in the next [`Last`](bevy_app::Last) schedule.
+    /// Prefer setting [`Window::resize_constraints`] from within systems, this is meant for code
+    /// that already has access to [`WinitWindows`]. Does nothing if the entity has no window.
+    pub fn set_resize_constraints(&mut self, entity: Entity, constraints: WindowResizeConstraints) {
+        if let Some(window) = self.get_window(entity) {
+            let (min_inner_size, max_inner_size) = inner_size_limits(&constraints);
+            window.set_min_inner_size(Some(min_inner_size));
+            window.set_max_inner_size(max_inner_size);
+            self.resize_constraints.insert(entity, constraints);
+        }
+    }
+
+    /// Changes the minimum inner size of the window associated with the entity, in logical
+    /// pixels, keeping its current maximum.
+    ///
+    /// The constraints are validated with [`WindowResizeConstraints::check_constraints`], so a
+    /// minimum above the maximum raises the maximum to match and logs a warning. Prefer setting
+    /// [`Window::resize_constraints`] from within systems, which overrides this when it changes.
+    /// Does nothing if the entity has no window.
+    pub fn set_min_inner_size(&mut self, entity: Entity, min_inner_size: Vec2) {
+        let Some(mut constraints) = self.resize_constraints.get(&entity).copied() else {
+            return;
+        };
+        constraints.min_width = min_inner_size.x;
+        constraints.min_height = min_inner_size.y;
+        self.set_resize_constraints(entity, constraints.check_constraints());
+    }
+
+    /// Changes or clears the maximum inner size of the window associated with the entity, in
+    /// logical pixels, keeping its current minimum.
+    ///
+    /// The constraints are validated with [`WindowResizeConstraints::check_constraints`], so a
+    /// maximum below the minimum is raised to the minimum and logs a warning. Prefer setting
+    /// [`Window::resize_constraints`] from within systems, which overrides this when it changes.
+    /// Does nothing if the entity has no window.
+    pub fn set_max_inner_size(&mut self, entity: Entity, max_inner_size: Option<Vec2>) {
+        let Some(mut constraints) = self.resize_constraints.get(&entity).copied() else {
+            return;
+        };
+        let max_inner_size = max_inner_size.unwrap_or(Vec2::INFINITY);
+        constraints.max_width = max_inner_size.x;
+        constraints.max_height = max_inner_size.y;
+        self.set_resize_constraints(entity, constraints.check_constraints());
+    }
+
+    /// Applies several changes to the window associated with the entity at once, ordered to
+    /// minimize intermediate repaints and resizes, e.g. when changing the title, size and
+    /// decorations together.
+    ///
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_math::Vec2;
+    /// # use bevy_window::PrimaryWindow;
+    /// # use bevy_winit::WinitWindows;
+    /// fn enter_compact_mode(
+    ///     primary_window: Single<Entity, With<PrimaryWindow>>,
+    ///     mut winit_windows: NonSendMut<WinitWindows>,
+    /// ) {
+    ///     winit_windows.update_window(*primary_window, |update| {
+    ///         update
+    ///             .title("Compact")
+    ///             .decorations(false)
+    ///             .inner_size(Vec2::new(320., 240.));
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// `winit` can't apply changes atomically, so this orders them: a window being hidden is
+    /// hidden first and a window being shown is shown last, and decorations are changed before
+    /// the size is requested so the client area only resizes once. Resizes the platform reports
+    /// in between are held back until the window reaches the requested size, or for
+    /// [`BATCHED_RESIZE_TIMEOUT`] at most, so only the final size is reported with a
+    /// [`WindowResized`](bevy_window::WindowResized) event.
+    ///
+    /// The changes are written back to the [`Window`] component in the next update, unless it
+    /// was changed in the meantime, and the title is applied like with
+    /// [`WinitWindows::set_title`].
+    ///
+    /// Returns `false` if the entity has no window.
+    pub fn update_window(&mut self, entity: Entity, f: impl FnOnce(&mut WindowUpdate)) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+        let mut update = WindowUpdate::default();
+        f(&mut update);
+
+        let resize_target = match (update.inner_size, update.decorations) {
+            (Some(size), _) => Some(Some(
+                LogicalSize::new(size.x, size.y).to_physical::<u32>(window.scale_factor()),
+            )),
+            // The inner size is only kept for windows that are neither maximized nor fullscreen.
+            (None, Some(_)) => Some(
+                (!window.is_maximized() && window.fullscreen().is_none())
+                    .then(|| window.inner_size()),
+            ),
+            (None, None) => None,
+        };
+        if let Some(target) = resize_target {
+            self.held_resizes
+                .insert(entity, HeldResize::new(target, Instant::now()));
+        }
+
+        if let Some(title) = &update.title {
+            self.set_title(entity, title);
+        }
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+        if update.visible == Some(false) {
+            window.set_visible(false);
+        }
+        if let Some(window_level) = update.window_level {
+            window.set_window_level(convert_window_level(window_level, window.has_focus()));
+        }
+        match (update.decorations, update.inner_size) {
+            (Some(decorations), Some(_)) => window.set_decorations(decorations),
+            (Some(decorations), None) => set_decorations_keeping_inner_size(window, decorations),
+            (None, _) => {}
+        }
+        if let Some(size) = update.inner_size {
+            let _ = window.request_inner_size(LogicalSize::new(size.x, size.y));
+        }
+        if let Some(position) = update.position {
+            window.set_outer_position(PhysicalPosition::new(position.x, position.y));
+        }
+        if update.visible == Some(true) {
+            window.set_visible(true);
+        }
+        self.window_updates.entry(entity).or_default().merge(update);
+        true
+    }
+
+    /// Holds back a resize reported by the platform while a batch from
+    /// [`WinitWindows::update_window`] settles, and returns whether it was held.
+    ///
+    /// The hold ends once the requested size is reported, which is then handled as usual.
+    pub(crate) fn hold_resize(&mut self, entity: Entity, size: PhysicalSize<u32>) -> bool {
+        let Some(held) = self.held_resizes.get_mut(&entity) else {
+            return false;
+        };
+        if held.target == Some(size) {
+            self.held_resizes.remove(&entity);
+            return false;
+        }
+        held.physical_size = Some(size);
+        true
+    }
+
+    /// Shows or hides the decorations of the window associated with the entity, keeping the
+    /// size of its client area.
+    ///
+    /// Prefer setting [`Window::decorations`] from within systems, which does the same. Does
+    /// nothing if the entity has no window.
+    pub fn set_decorations(&self, entity: Entity, decorations: bool) {
+        if let Some(window) = self.get_window(entity) {
+            set_decorations_keeping_inner_size(window, decorations);
+        }
+    }
+
+    /// Asks the system to leave input to the window associated with the entity while it is
+    /// focused and fullscreen, instead of handling system shortcuts and UI itself, e.g. for
+    /// kiosks and fullscreen games.
+    ///
+    /// Returns `false` if the entity has no window or if the platform doesn't allow it, in which
+    /// case nothing is changed and a warning is logged once.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Hides the Dock and menu bar in borderless fullscreen, so they can't be
+    ///   revealed with the pointer. Shortcuts like Cmd-Tab are still handled by the system.
+    ///   Takes effect the next time the window enters borderless fullscreen.
+    /// - **Others:** Unsupported, `winit` doesn't expose keyboard grabs or shortcut inhibition
+    ///   (e.g. of the Windows key).
+    pub fn set_key_grab(&self, entity: Entity, key_grab: bool) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+
+        #[cfg(target_os = "macos")]
+        {
+            use winit::platform::macos::WindowExtMacOS;
+            window.set_borderless_game(key_grab);
+            true
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, key_grab);
+            bevy_log::warn_once!("`WinitWindows::set_key_grab` is only supported on macOS");
+            false
+        }
+    }
+
+    /// Selects the next tab in the native tab group of the window associated with the entity.
+    ///
+    /// Windows are grouped into tabs by [`Window::tabbing_identifier`]. Does nothing if the
+    /// entity has no window or on platforms other than macOS.
+    pub fn select_next_tab(&self, entity: Entity) {
+        #[cfg(target_os = "macos")]
+        if let Some(window) = self.get_window(entity) {
+            use winit::platform::macos::WindowExtMacOS;
+            window.select_next_tab();
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        let _ = entity;
+    }
+
+    /// Selects the previous tab in the native tab group of the window associated with the entity.
+    ///
+    /// Does nothing if the entity has no window or on platforms other than macOS.
+    pub fn select_previous_tab(&self, entity: Entity) {
+        #[cfg(target_os = "macos")]
+        if let Some(window) = self.get_window(entity) {
+            use winit::platform::macos::WindowExtMacOS;
+            window.select_previous_tab();
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        let _ = entity;
+    }
+
+    /// Selects the tab at the given index in the native tab group of the window associated with
+    /// the entity.
+    ///
+    /// Does nothing if the index is out of bounds, if the entity has no window, or on platforms
+    /// other than macOS.
+    pub fn select_tab_at_index(&self, entity: Entity, index: usize) {
+        #[cfg(target_os = "macos")]
+        if let Some(window) = self.get_window(entity) {
+            use winit::platform::macos::WindowExtMacOS;
+            window.select_tab_at_index(index);
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        let _ = (entity, index);
+    }
+
+    /// Returns the number of tabs in the native tab group of the window associated with the
+    /// entity.
+    ///
+    /// Returns `None` if the entity has no window or on platforms other than macOS.
+    pub fn num_tabs(&self, entity: Entity) -> Option<usize> {
+        #[cfg(target_os = "macos")]
+        {
+            use winit::platform::macos::WindowExtMacOS;
+            self.get_window(entity).map(|window| window.num_tabs())
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = entity;
+            None
+        }
+    }
+
+    /// Shows a badge on the taskbar or dock entry of the window associated with the entity, e.g.
+    /// the number of unread messages.
+    ///
+    /// On platforms with a single dock entry per app, the badge applies to the whole app rather
+    /// than to the window. Returns `false` if the entity has no window or if the badge isn't
+    /// supported, in which case a warning is logged once.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Sets the badge label of the app's dock tile. [`Badge::Icon`] is unsupported,
+    ///   as the dock tile only takes a label.
+    /// - **Windows:** Unsupported. Taskbar overlay icons can only be set through the
+    ///   `ITaskbarList3` COM interface, which needs unsafe calls that this crate forbids.
+    /// - **Other platforms:** Unsupported.
+    pub fn set_badge(&self, entity: Entity, badge: Badge) -> bool {
+        if self.get_window(entity).is_none() {
+            return false;
+        }
+
+        #[cfg(target_os = "macos")]
+        match badge {
+            Badge::None => return crate::macos::set_badge_label(None),
+            Badge::Count(count) => {
+                return crate::macos::set_badge_label(Some(&count.to_string()));
+            }
+            Badge::Icon { .. } => {}
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        let _ = badge;
+        bevy_log::warn_once!(
+            "`WinitWindows::set_badge` doesn't support this badge on this platform"
+        );
+        false
+    }
+
+    /// Sets the backdrop drawn behind the window associated with the entity.
+    ///
+    /// Prefer setting [`Window::system_backdrop`] from within systems. Does nothing if the
+    /// entity has no window or on unsupported platforms.
+    pub fn set_backdrop(&self, entity: Entity, backdrop: WindowBackdrop) {
+        if let Some(window) = self.get_window(entity) {
+            set_backdrop(window, backdrop);
+        }
+    }
+
+    /// Sets the color of the border of the window associated with the entity.
+    ///
+    /// Prefer setting [`Window::border_color`] from within systems. Does nothing if the entity
+    /// has no window or on platforms other than Windows 11.
+    pub fn set_border_color(&self, entity: Entity, color: WindowChromeColor) {
+        if let Some(window) = self.get_window(entity) {
+            set_chrome_colors(window, Some(color), None, None);
+        }
+    }
+
+    /// Sets the background color of the title bar of the window associated with the entity.
+    ///
+    /// Prefer setting [`Window::title_background_color`] from within systems. Does nothing if
+    /// the entity has no window or on platforms other than Windows 11.
+    pub fn set_title_background_color(&self, entity: Entity, color: WindowChromeColor) {
+        if let Some(window) = self.get_window(entity) {
+            set_chrome_colors(window, None, Some(color), None);
+        }
+    }
+
+    /// Sets the color of the title text of the window associated with the entity.
+    ///
+    /// Prefer setting [`Window::title_text_color`] from within systems. Does nothing if the
+    /// entity has no window or on platforms other than Windows 11.
+    pub fn set_title_text_color(&self, entity: Entity, color: WindowChromeColor) {
+        if let Some(window) = self.get_window(entity) {
+            set_chrome_colors(window, None, None, Some(color));
+        }
+    }
+
+    /// Changes the transparency of the window associated with the entity after creation.
+    ///
+    /// Returns `false` if the entity has no window or if the platform is known to only honor
+    /// transparency when the window is created, in which case nothing is changed and the window
+    /// has to be recreated with [`Window::transparent`] set instead. A return value of `true`
+    /// only means the change was forwarded to the backend, which treats it as a hint.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Web / iOS / Android:** Unsupported, always returns `false`.
+    /// - **macOS:** This will reset the window's background color.
+    pub fn set_transparent(&self, entity: Entity, transparent: bool) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+
+        if cfg!(any(
+            target_arch = "wasm32",
+            target_os = "ios",
+            target_os = "android"
+        )) {
+            return false;
+        }
+
+        let is_x11 = window.window_handle().is_ok_and(|handle| {
+            matches!(
+                handle.as_raw(),
+                RawWindowHandle::Xlib(_) | RawWindowHandle::Xcb(_)
+            )
+        });
+        if is_x11 {
+            return false;
+        }
+
+        window.set_transparent(transparent);
+        true
+    }
+
+    /// Sets a uniform opacity for the whole window associated with the entity, dimming opaque
+    /// content as well.
+    ///
+    /// This is separate from [`Window::transparent`], which enables per-pixel transparency of the
+    /// rendered content when the window is created. `opacity` is clamped to `0.0..=1.0`.
+    ///
+    /// Returns `false` and logs a warning once if the platform doesn't support it, or `false` if
+    /// the entity has no window or the opacity couldn't be applied.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sets the `_NET_WM_WINDOW_OPACITY` property, which is applied by the compositor,
+    ///   so it has no effect without one. Requires the `x11` feature.
+    /// - **Web:** Sets the CSS `opacity` of the canvas.
+    /// - **Windows / macOS / Wayland / iOS / Android:** Unsupported.
+    pub fn set_window_opacity(&self, entity: Entity, opacity: f32) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+        let opacity = opacity.clamp(0.0, 1.0);
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            use winit::platform::web::WindowExtWebSys;
+            window.canvas().is_some_and(|canvas| {
+                canvas
+                    .style()
+                    .set_property("opacity", &opacity.to_string())
+                    .is_ok()
+            })
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Ok(handle) = window.window_handle() else {
+                return false;
+            };
+            match handle.as_raw() {
+                #[cfg(all(
+                    feature = "x11",
+                    any(
+                        target_os = "linux",
+                        target_os = "dragonfly",
+                        target_os = "freebsd",
+                        target_os = "netbsd",
+                        target_os = "openbsd"
+                    )
+                ))]
+                RawWindowHandle::Xlib(_) | RawWindowHandle::Xcb(_) => x11_window_id(window)
+                    .is_some_and(|id| {
+                        crate::x11::connection().is_some_and(|x11| x11.set_opacity(id, opacity))
+                    }),
+                _ => {
+                    let _ = opacity;
+                    bevy_log::warn_once!(
+                        "`WinitWindows::set_window_opacity` is unsupported on this platform"
+                    );
+                    false
+                }
+            }
+        }
+    }
+
+    /// Sets whether the window associated with the entity appears on all virtual desktops or
+    /// workspaces, e.g. for overlays that should follow the user across desktops.
+    ///
+    /// This is best-effort: returns `false` if the entity has no window or the request couldn't
+    /// be made, and logs a warning once if the platform isn't supported.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Sets the `canJoinAllSpaces` collection behavior of the `NSWindow`. Must be
+    ///   called on the main thread, like all [`WinitWindows`] methods.
+    /// - **X11:** Moves the window to desktop `0xFFFFFFFF` with `_NET_WM_DESKTOP`, or back to the
+    ///   current desktop. Has no effect with window managers that don't follow the EWMH. Requires
+    ///   the `x11` feature.
+    /// - **Windows / Wayland / iOS / Android / Web:** Unsupported.
+    pub fn set_visible_on_all_workspaces(&self, entity: Entity, visible: bool) -> bool {
+        let Some(window) = self.get_window(entity) else {
+            return false;
+        };
+
+        #[cfg(target_os = "macos")]
+        {
+            crate::macos::set_visible_on_all_workspaces(window, visible)
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        {
+            #[cfg(all(
+                feature = "x11",
+                any(
+                    target_os = "linux",
+                    target_os = "dragonfly",
+                    target_os = "freebsd",
+                    target_os = "netbsd",
+                    target_os = "openbsd"
+                )
+            ))]
+            if let Some(id) = x11_window_id(window) {
+                return crate::x11::connection()
+                    .is_some_and(|x11| x11.set_visible_on_all_workspaces(id, visible));
+            }
+
+            let _ = (window, visible);
+            bevy_log::warn_once!(
+                "`WinitWindows::set_visible_on_all_workspaces` is unsupported on this platform"
+            );
+            false
+        }
+    }
+
+    /// Returns the components of a window that briefly shows `number` on the selected monitor,
+    /// e.g. for an "identify displays" button in a display settings screen.
+    ///
+    /// The window is small, borderless, always on top and click-through, and centered on the
+    /// monitor. It is closed after [`MONITOR_IDENTIFIER_DURATION`], use [`MonitorIdentifier::new`]
+    /// for a different duration.
+    ///
+    /// ```no_run
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_window::{Monitor, MonitorSelection};
+    /// # use bevy_winit::WinitWindows;
+    /// fn identify_displays(mut commands: Commands, monitors: Query<Entity, With<Monitor>>) {
+    ///     for (index, monitor) in monitors.iter().enumerate() {
+    ///         commands.spawn(WinitWindows::flash_monitor_identifier(
+    ///             MonitorSelection::Entity(monitor),
+    ///             index as u32 + 1,
+    ///         ));
+    ///     }
+    /// }
+    /// ```
+    pub fn flash_monitor_identifier(
+        monitor: MonitorSelection,
+        number: u32,
+    ) -> (Window, MonitorIdentifier) {
+        let mut window = Window {
+            title: number.to_string(),
+            resolution: WindowResolution::new(160., 160.),
+            position: WindowPosition::Centered(monitor),
+            window_level: WindowLevel::AlwaysOnTop,
+            visible: true,
+            decorations: false,
+            ..Window::utility()
+        };
+        window.cursor_options.hit_test = false;
+        (
+            window,
+            MonitorIdentifier::new(number, MONITOR_IDENTIFIER_DURATION),
+        )
+    }
+}
+
+/// Returns the X11 window ID of a window, or `None` if it isn't an X11 window.
+#[cfg(all(
+    feature = "x11",
+    any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    )
+))]
+pub(crate) fn x11_window_id(window: &WinitWindow) -> Option<u32> {
+    match window.window_handle().ok()?.as_raw() {
+        RawWindowHandle::Xlib(handle) => u32::try_from(handle.window).ok(),
+        RawWindowHandle::Xcb(handle) => Some(handle.window.get()),
+        _ => None,
+    }
+}
+
+/// Applies the given frame colors to a window, leaving the ones that are `None` unchanged.
+///
+/// Only has an effect on Windows.
+pub(crate) fn set_chrome_colors(
+    winit_window: &WinitWindow,
+    border: Option<WindowChromeColor>,
+    title_background: Option<WindowChromeColor>,
+    title_text: Option<WindowChromeColor>,
+) {
+    #[cfg(target_os = "windows")]
+    {
+        use crate::converters::convert_chrome_color;
+        use winit::platform::windows::{Color, WindowExtWindows};
+        if let Some(color) = border {
+            winit_window.set_border_color(convert_chrome_color(color));
+        }
+        if let Some(color) = title_background {
+            winit_window.set_title_background_color(convert_chrome_color(color));
+        }
+        if let Some(color) = title_text {
+            winit_window
+                .set_title_text_color(convert_chrome_color(color).unwrap_or(Color::SYSTEM_DEFAULT));
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    let _ = (winit_window, border, title_background, title_text);
+}
+
+/// Applies [`Window::system_backdrop`] to a window.
+pub(crate) fn set_backdrop(winit_window: &WinitWindow, backdrop: WindowBackdrop) {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows;
+        winit_window.set_system_backdrop(crate::converters::convert_system_backdrop(backdrop));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    winit_window.set_blur(backdrop.is_blurred());
+}
+
+/// Toggles the decorations of a window and restores its prior inner size afterwards.
+///
+/// Some platforms (e.g. Windows and X11) keep the outer size when decorations are toggled,
+/// which would otherwise grow or shrink the client area by the size of the frame. The size is
+/// requested unconditionally since X11 window managers apply the new frame asynchronously.
+/// Maximized and fullscreen windows are left alone, their size is managed by the system.
+pub(crate) fn set_decorations_keeping_inner_size(winit_window: &WinitWindow, decorations: bool) {
+    let inner_size = winit_window.inner_size();
+    winit_window.set_decorations(decorations);
+    if !winit_window.is_maximized() && winit_window.fullscreen().is_none() {
+        let _ = winit_window.request_inner_size(inner_size);
+    }
+}
+
+/// Converts resize constraints into the minimum and maximum inner size of a `winit` window.
+///
+/// The maximum is `None` unless both the maximum width and height are finite.
+pub(crate) fn inner_size_limits(
+    constraints: &WindowResizeConstraints,
+) -> (LogicalSize<f32>, Option<LogicalSize<f32>>) {
+    let constraints = constraints.check_constraints();
+    let min_inner_size = LogicalSize {
+        width: constraints.min_width,
+        height: constraints.min_height,
+    };
+    let max_inner_size = (constraints.max_width.is_finite() && constraints.max_height.is_finite())
+        .then_some(LogicalSize {
+            width: constraints.max_width,
+            height: constraints.max_height,
+        });
+    (min_inner_size, max_inner_size)
+}
+
+/// Gets the "best" video mode which fits the given dimensions.
+///
+/// The heuristic for "best" prioritizes width, height, and refresh rate in that order.
+/// Returns `None` if the monitor reports no video modes.
+///
+/// If `min_bit_depth` is provided, modes with at least that bit depth are preferred, and the
+/// bit depth is used as the final tie-break. If no mode meets the minimum, the modes with the
+/// closest bit depth are considered instead.
+pub fn get_fitting_videomode(
+    monitors: &WinitMonitors,
+    monitor: &MonitorHandle,
+    width: u32,
+    height: u32,
+    min_bit_depth: Option<u16>,
+) -> Option<VideoModeHandle> {
+    monitors
+        .video_modes(monitor)
+        .iter()
+        .min_by_key(|x| {
+            fitting_videomode_key(
+                UVec2::new(x.size().width, x.size().height),
+                x.refresh_rate_millihertz(),
+                x.bit_depth(),
+                UVec2::new(width, height),
+                min_bit_depth,
+            )
+        })
+        .cloned()
+}
+
+/// Returns the [`WindowMode`] matching the fullscreen state a window actually has, or `None` if
+/// `mode` already matches it.
+///
+/// The window may not get the requested mode, e.g. when its monitor is unavailable, and the OS
+/// can change it later, e.g. when the user leaves fullscreen with a keyboard shortcut. The
+/// monitor the window is on is reported as [`MonitorSelection::Current`].
+pub(crate) fn actual_window_mode(
+    mode: WindowMode,
+    fullscreen: Option<&Fullscreen>,
+) -> Option<WindowMode> {
+    match (mode, fullscreen) {
+        (WindowMode::Windowed, None)
+        | (WindowMode::BorderlessFullscreen(_), Some(Fullscreen::Borderless(_)))
+        | (
+            WindowMode::Fullscreen(_) | WindowMode::SizedFullscreen(_),
+            Some(Fullscreen::Exclusive(_)),
+        ) => None,
+        (_, None) => Some(WindowMode::Windowed),
+        (_, Some(Fullscreen::Borderless(_))) => {
+            Some(WindowMode::BorderlessFullscreen(MonitorSelection::Current))
+        }
+        (_, Some(Fullscreen::Exclusive(_))) => {
+            Some(WindowMode::Fullscreen(MonitorSelection::Current))
+        }
+    }
+}
+
+/// The sort key of [`get_fitting_videomode`], the smallest key is the best fit.
+fn fitting_videomode_key(
+    size: UVec2,
+    refresh_rate_millihertz: u32,
+    bit_depth: u16,
+    target: UVec2,
+    min_bit_depth: Option<u16>,
+) -> (u16, u32, u32, Reverse<u32>, Reverse<u16>) {
+    (
+        min_bit_depth.map_or(0, |min| min.saturating_sub(bit_depth)),
+        size.x.abs_diff(target.x),
+        size.y.abs_diff(target.y),
+        Reverse(refresh_rate_millihertz),
+        Reverse(bit_depth),
+    )
+}
+
+/// Gets the "best" video-mode handle from a monitor.
+///
+/// The heuristic for "best" prioritizes width, height, and refresh rate in that order.
+/// Returns `None` if the monitor reports no video modes.
+///
+/// If `min_bit_depth` is provided, modes with at least that bit depth are preferred, and the
+/// bit depth is used as the final tie-break. If no mode meets the minimum, the modes with the
+/// closest bit depth are considered instead.
+pub fn get_best_videomode(
+    monitors: &WinitMonitors,
+    monitor: &MonitorHandle,
+    min_bit_depth: Option<u16>,
+) -> Option<VideoModeHandle> {
+    monitors
+        .video_modes(monitor)
+        .iter()
+        .max_by_key(|x| {
+            (
+                Reverse(bit_depth_shortfall(x, min_bit_depth)),
+                x.size(),
+                x.refresh_rate_millihertz(),
+                x.bit_depth(),
+            )
+        })
+        .cloned()
+}
+
+/// Gets the video-mode handle a monitor is currently running at, e.g. for a fullscreen mode
+/// that matches the desktop.
+///
+/// Unlike [`get_best_videomode`], this is the mode with the monitor's current size and refresh
+/// rate, which may be lower than the best one. The closest refresh rate is used if none matches
+/// exactly, e.g. 59.94 Hz modes on a monitor that reports 60 Hz, and the highest bit depth
+/// breaks ties. Returns `None` if no video mode has the current size.
+pub fn current_video_mode(
+    monitors: &WinitMonitors,
+    monitor: &MonitorHandle,
+) -> Option<VideoModeHandle> {
+    let size = monitor.size();
+    let refresh_rate = monitor.refresh_rate_millihertz();
+    monitors
+        .video_modes(monitor)
+        .iter()
+        .filter(|x| x.size() == size)
+        .min_by_key(|x| {
+            (
+                refresh_rate.map(|rate| x.refresh_rate_millihertz().abs_diff(rate)),
+                Reverse(x.bit_depth()),
+            )
+        })
+        .cloned()
+}
+
+/// Degrades an exclusive fullscreen `mode` gracefully if its monitor couldn't be selected,
+/// e.g. right after a monitor was (dis)connected, instead of panicking.
+///
+/// Falls back to borderless fullscreen on the `primary` monitor, or to windowed mode if there
+/// is no monitor at all, with a warning. Other modes and resolved monitors are returned as is.
+pub(crate) fn fullscreen_monitor_fallback(
+    mode: WindowMode,
+    selected_monitor: Option<MonitorHandle>,
+    primary_monitor: Option<MonitorHandle>,
+) -> (WindowMode, Option<MonitorHandle>) {
+    match &mode {
+        WindowMode::Fullscreen(monitor_selection)
+        | WindowMode::SizedFullscreen(monitor_selection)
+            if selected_monitor.is_none() =>
+        {
+            if primary_monitor.is_some() {
+                warn!(
+                    "Could not find monitor for {monitor_selection:?}, falling back to borderless fullscreen on the primary monitor"
+                );
+                (
+                    WindowMode::BorderlessFullscreen(MonitorSelection::Primary),
+                    primary_monitor,
+                )
+            } else {
+                warn!(
+                    "Could not find monitor for {monitor_selection:?}, falling back to windowed mode"
+                );
+                (WindowMode::Windowed, None)
+            }
+        }
+        _ => (mode, selected_monitor),
+    }
+}
+
+/// Uses exclusive fullscreen with `videomode` if there is one, and falls back to borderless
+/// fullscreen on `monitor` otherwise, e.g. on virtual displays that report no video modes.
+pub(crate) fn exclusive_or_borderless(
+    videomode: Option<VideoModeHandle>,
+    monitor: &MonitorHandle,
+) -> Fullscreen {
+    match videomode {
+        Some(videomode) => Fullscreen::Exclusive(videomode),
+        None => {
+            warn!(
+                "Monitor {:?} reports no video modes, falling back to borderless fullscreen",
+                monitor.name()
+            );
+            Fullscreen::Borderless(Some(monitor.clone()))
+        }
+    }
+}
+
+/// How many bits a video mode falls short of the requested minimum bit depth.
+fn bit_depth_shortfall(mode: &VideoModeHandle, min_bit_depth: Option<u16>) -> u16 {
+    min_bit_depth.map_or(0, |min| min.saturating_sub(mode.bit_depth()))
+}
+
+/// Applies `grab_mode` to the window, falling back to the alternate grab mode if it is
+/// unsupported, and returns the mode that took effect.
+pub(crate) fn attempt_grab(
+    winit_window: &WinitWindow,
+    grab_mode: CursorGrabMode,
+    confine_region: Option<Rect>,
+) -> Result<CursorGrabMode, ExternalError> {
+    let grab_result = match grab_mode {
+        CursorGrabMode::None => winit_window
+            .set_cursor_grab(WinitCursorGrabMode::None)
+            .map(|()| CursorGrabMode::None),
+        CursorGrabMode::Confined => winit_window
+            .set_cursor_grab(WinitCursorGrabMode::Confined)
+            .map(|()| CursorGrabMode::Confined)
+            .or_else(|_e| {
+                winit_window
+                    .set_cursor_grab(WinitCursorGrabMode::Locked)
+                    .map(|()| CursorGrabMode::Locked)
+            }),
+        CursorGrabMode::Locked => winit_window
+            .set_cursor_grab(WinitCursorGrabMode::Locked)
+            .map(|()| CursorGrabMode::Locked)
+            .or_else(|_e| {
+                winit_window
+                    .set_cursor_grab(WinitCursorGrabMode::Confined)
+                    .map(|()| CursorGrabMode::Confined)
+            }),
+    };
+
+    match grab_result {
+        Err(err) => {
+            let err_desc = match grab_mode {
+                CursorGrabMode::Confined | CursorGrabMode::Locked => "grab",
+                CursorGrabMode::None => "ungrab",
+            };
+
+            tracing::error!("Unable to {} cursor: {}", err_desc, err);
+            Err(err)
+        }
+        Ok(effective_mode) => {
+            if let (CursorGrabMode::Confined, Some(region)) = (effective_mode, confine_region) {
+                // `winit` can only confine the cursor to the whole window, the region is enforced
+                // by moving the cursor back whenever it leaves, see `confined_cursor_position`.
+                bevy_log::debug!("Confining the cursor to {region:?} within the window");
+            }
+            Ok(effective_mode)
+        }
+    }
+}
+
+/// Returns where to move a cursor at the logical `position` to keep it inside the confine
+/// region of a [`CursorGrabMode::Confined`] grab, or `None` if it doesn't have to move.
+pub(crate) fn confined_cursor_position(
+    grab_mode: CursorGrabMode,
+    confine_region: Option<Rect>,
+    position: Vec2,
+) -> Option<Vec2> {
+    let region = confine_region.filter(|_| grab_mode == CursorGrabMode::Confined)?;
+    let clamped = position.clamp(region.min, region.max);
+    (clamped != position).then_some(clamped)
+}
+
+/// Resolves the scale factor a window with the given resolution uses on `monitor`.
+///
+/// This is the [scale factor override](WindowResolution::scale_factor_override) if there is one,
+/// and the scale factor of the monitor otherwise. The monitor is used instead of
+/// [`WindowResolution::scale_factor`] since the latter isn't populated before the window has
+/// been created. Falls back to `1.0` if there is neither an override nor a monitor.
+pub fn resolve_scale_factor(resolution: &WindowResolution, monitor: Option<&MonitorHandle>) -> f64 {
+    match resolution.scale_factor_override() {
+        Some(scale_factor_override) => scale_factor_override as f64,
+        None => monitor.map_or(1.0, MonitorHandle::scale_factor),
+    }
+}
+
+/// Compute the physical window position for a given [`WindowPosition`].
+///
+/// If `keep_on_screen` is set, a [`WindowPosition::At`] position is moved so the window lies
+/// entirely on a monitor, see [`Window::keep_on_screen`].
+// Ideally we could generify this across window backends, but we only really have winit atm
+// so whatever.
+pub fn winit_window_position(
+    position: &WindowPosition,
+    resolution: &WindowResolution,
+    keep_on_screen: bool,
+    monitors: &WinitMonitors,
+    primary_monitor: Option<MonitorHandle>,
+    current_monitor: Option<MonitorHandle>,
+) -> Option<PhysicalPosition<i32>> {
+    match position {
+        WindowPosition::Automatic => {
+            // Window manager will handle position
+            None
+        }
+        WindowPosition::Centered(monitor_selection) => {
+            let maybe_monitor = select_monitor(
+                monitors,
+                primary_monitor,
+                current_monitor,
+                monitor_selection,
+            );
+
+            if let Some(monitor) = maybe_monitor {
+                let scale_factor = resolve_scale_factor(resolution, Some(&monitor));
+                let monitor_position = monitor.position();
+                let monitor_size = monitor.size();
+                Some(centered_position(
+                    resolution,
+                    scale_factor,
+                    IVec2::new(monitor_position.x, monitor_position.y),
+                    UVec2::new(monitor_size.width, monitor_size.height),
+                ))
+            } else if let Some(virtual_monitor) = monitors.virtual_monitor() {
+                Some(centered_position(
+                    resolution,
+                    virtual_monitor.resolve_scale_factor(resolution),
+                    virtual_monitor.position,
+                    virtual_monitor.size,
+                ))
+            } else {
+                warn!("Couldn't get monitor selected with: {monitor_selection:?}");
+                None
+            }
+        }
+        WindowPosition::At(position) => {
+            if !keep_on_screen {
+                return Some(PhysicalPosition::new(position.x, position.y));
+            }
+            // The window doesn't know its scale factor yet, so use the one of the monitor it
+            // would be placed on.
+            let monitor = monitors
+                .monitor_at(*position)
+                .and_then(|entity| monitors.find_entity(entity))
+                .or(primary_monitor);
+            let scale_factor = match (monitor, monitors.virtual_monitor()) {
+                (None, Some(virtual_monitor)) => virtual_monitor.resolve_scale_factor(resolution),
+                (monitor, _) => resolve_scale_factor(resolution, monitor.as_ref()),
+            };
+            let size: (u32, u32) = LogicalSize::new(resolution.width(), resolution.height())
+                .to_physical::<u32>(scale_factor)
+                .into();
+            let position = monitors.clamp_to_monitors(*position, size.into());
+            Some(PhysicalPosition::new(position.x, position.y))
+        }
+        WindowPosition::AnchoredToMonitor {
+            monitor: monitor_selection,
+            anchor,
+            margin,
+        } => {
+            let maybe_monitor = select_monitor(
+                monitors,
+                primary_monitor,
+                current_monitor,
+                monitor_selection,
+            );
+
+            if let Some(monitor) = maybe_monitor {
+                let scale_factor = resolve_scale_factor(resolution, Some(&monitor));
+                let monitor_position = monitor.position();
+                let monitor_size = monitor.size();
+                Some(anchored_position(
+                    resolution,
+                    scale_factor,
+                    IVec2::new(monitor_position.x, monitor_position.y),
+                    UVec2::new(monitor_size.width, monitor_size.height),
+                    *anchor,
+                    *margin,
+                ))
+            } else if let Some(virtual_monitor) = monitors.virtual_monitor() {
+                Some(anchored_position(
+                    resolution,
+                    virtual_monitor.resolve_scale_factor(resolution),
+                    virtual_monitor.position,
+                    virtual_monitor.size,
+                    *anchor,
+                    *margin,
+                ))
+            } else {
+                warn!("Couldn't get monitor selected with: {monitor_selection:?}");
+                None
+            }
+        }
+    }
+}
+
+/// Returns the physical position that docks a window with the given resolution to the `anchor`
+/// of a monitor, `margin` pixels away from the edges it is docked to.
+fn anchored_position(
+    resolution: &WindowResolution,
+    scale_factor: f64,
+    monitor_position: IVec2,
+    monitor_size: UVec2,
+    anchor: MonitorAnchor,
+    margin: IVec2,
+) -> PhysicalPosition<i32> {
+    let (width, height): (u32, u32) = LogicalSize::new(resolution.width(), resolution.height())
+        .to_physical::<u32>(scale_factor)
+        .into();
+    let free_space = monitor_size.as_ivec2() - UVec2::new(width, height).as_ivec2();
+    let fraction = anchor.fraction();
+
+    // Move away from the edge the window touches; centered axes ignore the margin.
+    let offset = |fraction: f32, free_space: i32, margin: i32| {
+        let edge_offset = (free_space as f32 * fraction) as i32;
+        if fraction == 0.0 {
+            edge_offset + margin
+        } else if fraction == 1.0 {
+            edge_offset - margin
+        } else {
+            edge_offset
+        }
+    };
+
+    PhysicalPosition::new(
+        monitor_position.x + offset(fraction.x, free_space.x, margin.x),
+        monitor_position.y + offset(fraction.y, free_space.y, margin.y),
+    )
+}
+
+/// Returns the physical position and size of the work area of a monitor, the part that isn't
+/// covered by task bars or docks.
+///
+/// `winit` doesn't expose the work area, so this is the full monitor until it does.
+fn work_area(monitor: &MonitorHandle) -> (IVec2, UVec2) {
+    let position = monitor.position();
+    let size = monitor.size();
+    (
+        IVec2::new(position.x, position.y),
+        UVec2::new(size.width, size.height),
+    )
+}
+
+/// Returns the physical position that centers a window with the given resolution on a monitor.
+fn centered_position(
+    resolution: &WindowResolution,
+    scale_factor: f64,
+    monitor_position: IVec2,
+    monitor_size: UVec2,
+) -> PhysicalPosition<i32> {
+    // Logical to physical window size
+    let (width, height): (u32, u32) = LogicalSize::new(resolution.width(), resolution.height())
+        .to_physical::<u32>(scale_factor)
+        .into();
+
+    let position = PhysicalPosition {
+        x: monitor_size.x.saturating_sub(width) as f64 / 2. + monitor_position.x as f64,
+        y: monitor_size.y.saturating_sub(height) as f64 / 2. + monitor_position.y as f64,
+    };
+    position.cast::<i32>()
+}
+
+/// Returns the monitor an existing window is on, used to resolve [`MonitorSelection::Current`]
+/// at runtime.
+///
+/// Some platforms can't always report the current monitor, so this falls back to the monitor
+/// containing the largest part of the window, and then to the primary monitor.
+pub(crate) fn window_current_monitor(
+    monitors: &WinitMonitors,
+    winit_window: &WinitWindow,
+) -> Option<MonitorHandle> {
+    winit_window
+        .current_monitor()
+        .or_else(|| majority_monitor(monitors, winit_window))
+        .or_else(|| winit_window.primary_monitor())
+}
+
+/// Returns the monitor containing the largest part of a window, or `None` if the window's
+/// position is unknown or it doesn't overlap any monitor.
+pub(crate) fn majority_monitor(
+    monitors: &WinitMonitors,
+    winit_window: &WinitWindow,
+) -> Option<MonitorHandle> {
+    let position = winit_window.outer_position().ok()?;
+    let size = winit_window.outer_size();
+    let min = IVec2::new(position.x, position.y);
+    let rect = IRect::from_corners(min, min + UVec2::new(size.width, size.height).as_ivec2());
+    monitors.find_entity(monitors.monitor_containing_largest_area(rect)?)
+}
+
+/// Selects a monitor based on the given [`MonitorSelection`].
+pub fn select_monitor(
+    monitors: &WinitMonitors,
+    primary_monitor: Option<MonitorHandle>,
+    current_monitor: Option<MonitorHandle>,
+    monitor_selection: &MonitorSelection,
+) -> Option<MonitorHandle> {
+    use bevy_window::MonitorSelection::*;
+
+    match monitor_selection {
+        Current => {
+            if current_monitor.is_none() {
+                warn!("Can't find the current monitor!");
+            }
+            current_monitor
+        }
+        Primary => primary_monitor,
+        Index(n) => monitors.nth(*n),
+        Entity(entity) => monitors.find_entity(*entity),
+        Name(name) => monitors.find_name(name).or_else(|| {
+            warn!("Can't find monitor named {name:?}, falling back to the primary monitor");
+            primary_monitor
+        }),
+    }
+}
+
+/// Infers why a window was activated or deactivated, see [`ActivationReason`].
+///
+/// `previous` is the window's last activation state, if any. Deactivated windows that are
+/// minimized or occluded are assumed to have lost focus because of it, and windows that were
+/// deactivated by being minimized are assumed to be activated by being restored.
+pub(crate) fn activation_reason(
+    active: bool,
+    previous: Option<(bool, ActivationReason)>,
+    minimized: bool,
+    occluded: bool,
+) -> ActivationReason {
+    if active {
+        return match previous {
+            Some((false, ActivationReason::Minimized)) => ActivationReason::Minimized,
+            _ => ActivationReason::Focus,
+        };
+    }
+    if minimized {
+        ActivationReason::Minimized
+    } else if occluded {
+        ActivationReason::Occluded
+    } else {
+        ActivationReason::Focus
+    }
+}
+
+/// How far, relative to the target ratio, a window's aspect ratio may drift before
+/// [`Window::preserve_aspect_ratio`] corrects it.
+///
+/// Corrected sizes are rounded to whole pixels, so this must tolerate some error to avoid an
+/// endless series of corrections.
+const ASPECT_RATIO_EPSILON: f32 = 0.01;
+
+/// Returns the size a window resized from `previous` to `current` should have to keep the given
+/// aspect ratio, or `None` if it is already close enough.
+///
+/// The dimension that changed the most, relative to its previous value, is kept.
+pub(crate) fn aspect_corrected_size(
+    previous: Vec2,
+    current: Vec2,
+    aspect_ratio: f32,
+) -> Option<Vec2> {
+    if aspect_ratio <= 0. || current.cmple(Vec2::ZERO).any() {
+        return None;
+    }
+    let drift = (current.x / current.y) / aspect_ratio - 1.;
+    if drift.abs() <= ASPECT_RATIO_EPSILON {
+        return None;
+    }
+
+    let relative_change = ((current - previous) / previous.max(Vec2::ONE)).abs();
+    if relative_change.y > relative_change.x {
+        Some(Vec2::new(current.y * aspect_ratio, current.y))
+    } else {
+        Some(Vec2::new(current.x, current.x / aspect_ratio))
+    }
+}
+
+/// A region of a monitor that a window can be snapped to, see [`snap_rect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapRegion {
+    /// The whole monitor.
+    Full,
+    /// The left half of the monitor.
+    LeftHalf,
+    /// The right half of the monitor.
+    RightHalf,
+    /// The top half of the monitor.
+    TopHalf,
+    /// The bottom half of the monitor.
+    BottomHalf,
+    /// The top left quarter of the monitor.
+    TopLeft,
+    /// The top right quarter of the monitor.
+    TopRight,
+    /// The bottom left quarter of the monitor.
+    BottomLeft,
+    /// The bottom right quarter of the monitor.
+    BottomRight,
+}
+
+/// Computes the physical position and size of a window snapped to a [`SnapRegion`] of the
+/// selected monitor.
+///
+/// The result can be applied with [`WindowPosition::At`] and
+/// [`WindowResolution::set_physical_resolution`]. The size is grown to honor the minimum size of
+/// `resize_constraints` (which is in logical pixels) if the region would be too small, and the
+/// window is then kept on the monitor where possible.
+///
+/// Returns `None` if the monitor can't be resolved.
+///
+/// Note that `winit` doesn't expose the work area of a monitor, so the region doesn't account
+/// for task bars or docks.
+pub fn snap_rect(
+    monitors: &WinitMonitors,
+    primary_monitor: Option<MonitorHandle>,
+    current_monitor: Option<MonitorHandle>,
+    monitor_selection: &MonitorSelection,
+    region: SnapRegion,
+    resize_constraints: &WindowResizeConstraints,
+) -> Option<(IVec2, UVec2)> {
+    let monitor = select_monitor(
+        monitors,
+        primary_monitor,
+        current_monitor,
+        monitor_selection,
+    )?;
+    let monitor_position = monitor.position();
+    let monitor_position = IVec2::new(monitor_position.x, monitor_position.y);
+    let monitor_size = monitor.size();
+    let monitor_size = UVec2::new(monitor_size.width, monitor_size.height);
+    let half_size = monitor_size / 2;
+
+    let (offset, size) = match region {
+        SnapRegion::Full => (UVec2::ZERO, monitor_size),
+        SnapRegion::LeftHalf => (UVec2::ZERO, UVec2::new(half_size.x, monitor_size.y)),
+        SnapRegion::RightHalf => (
+            UVec2::new(half_size.x, 0),
+            UVec2::new(monitor_size.x - half_size.x, monitor_size.y),
+        ),
+        SnapRegion::TopHalf => (UVec2::ZERO, UVec2::new(monitor_size.x, half_size.y)),
+        SnapRegion::BottomHalf => (
+            UVec2::new(0, half_size.y),
+            UVec2::new(monitor_size.x, monitor_size.y - half_size.y),
+        ),
+        SnapRegion::TopLeft => (UVec2::ZERO, half_size),
+        SnapRegion::TopRight => (
+            UVec2::new(half_size.x, 0),
+            UVec2::new(monitor_size.x - half_size.x, half_size.y),
+        ),
+        SnapRegion::BottomLeft => (
+            UVec2::new(0, half_size.y),
+            UVec2::new(half_size.x, monitor_size.y - half_size.y),
+        ),
+        SnapRegion::BottomRight => (half_size, monitor_size - half_size),
+    };
+
+    let constraints = resize_constraints.check_constraints();
+    let scale_factor = monitor.scale_factor() as f32;
+    let min_size = UVec2::new(
+        (constraints.min_width * scale_factor).ceil() as u32,
+        (constraints.min_height * scale_factor).ceil() as u32,
+    );
+    let size = size.max(min_size);
+
+    // Keep the window on the monitor if honoring the minimum size made it overflow.
+    let max_position =
+        monitor_position + (monitor_size.as_ivec2() - size.as_ivec2()).max(IVec2::ZERO);
+    let position = (monitor_position + offset.as_ivec2()).min(max_position);
+
+    Some((position, size))
+}
+
+/// Computes a logical window size that takes `fraction` of the selected monitor in each
+/// dimension, together with the monitor's scale factor, e.g. `0.8` for a window covering 80% of
+/// the screen.
+///
+/// `fraction` is clamped to `0.0..=1.0`. The size can be applied with
+/// [`WindowResolution::set`] and combined with [`WindowPosition::Centered`] using the same
+/// monitor selection. Returns `None` if the monitor can't be resolved.
+///
+/// Note that `winit` doesn't expose the work area of a monitor, so the size doesn't account for
+/// task bars or docks.
+pub fn suggested_window_size(
+    monitors: &WinitMonitors,
+    primary_monitor: Option<MonitorHandle>,
+    current_monitor: Option<MonitorHandle>,
+    monitor_selection: &MonitorSelection,
+    fraction: f32,
+) -> Option<(Vec2, f64)> {
+    let monitor = select_monitor(
+        monitors,
+        primary_monitor,
+        current_monitor,
+        monitor_selection,
+    )?;
+    let scale_factor = monitor.scale_factor();
+    let size: LogicalSize<f32> = monitor.size().to_logical(scale_factor);
+    let fraction = fraction.clamp(0.0, 1.0);
+    Some((Vec2::new(size.width, size.height) * fraction, scale_factor))
+}
+
+/// Creates a [`Window`] that opens in borderless fullscreen on the selected monitor, with its
+/// resolution set to the native size of that monitor.
+///
+/// If the selection doesn't resolve to a connected monitor, e.g. a monitor index saved by an
+/// earlier run, a warning is logged and the primary monitor is used instead.
+/// [`MonitorSelection::Current`] is kept as is and resolved when the window is created, its
+/// resolution is taken from the primary monitor.
+pub fn native_fullscreen_window(
+    monitors: &WinitMonitors,
+    primary_monitor: Option<MonitorHandle>,
+    monitor_selection: MonitorSelection,
+) -> Window {
+    let (monitor_selection, monitor) = match monitor_selection {
+        MonitorSelection::Current => (MonitorSelection::Current, primary_monitor),
+        monitor_selection => {
+            match select_monitor(monitors, primary_monitor.clone(), None, &monitor_selection) {
+                Some(monitor) => (monitor_selection, Some(monitor)),
+                None => {
+                    warn!(
+                        "Can't find monitor for {monitor_selection:?}, falling back to the primary monitor"
+                    );
+                    (MonitorSelection::Primary, primary_monitor)
+                }
+            }
+        }
+    };
+
+    let mut window = Window {
+        mode: WindowMode::BorderlessFullscreen(monitor_selection.clone()),
+        position: WindowPosition::Centered(monitor_selection),
+        ..Default::default()
+    };
+    if let Some(monitor) = monitor {
+        let size = monitor.size();
+        window
+            .resolution
+            .set_scale_factor(monitor.scale_factor() as f32);
+        window
+            .resolution
+            .set_physical_resolution(size.width, size.height);
+    }
+    window
+}
+
+/// The fullscreen state a window actually got, see [`WinitWindows::fullscreen_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FullscreenState {
+    /// The window has exclusive control of its monitor, with its own video mode.
+    Exclusive,
+    /// The window covers its monitor without changing the video mode.
+    Borderless,
+    /// The window isn't fullscreen.
+    Windowed,
+}
+
+/// A window feature that isn't available on every platform, see [`WinitWindows::supports`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowFeature {
+    /// Locking the cursor in place with [`CursorGrabMode::Locked`].
+    CursorLock,
+    /// Confining the cursor to the window with [`CursorGrabMode::Confined`].
+    CursorConfine,
+    /// Moving the cursor with [`Window::set_cursor_position`].
+    CursorPosition,
+    /// Exclusive fullscreen with [`WindowMode::Fullscreen`].
+    ExclusiveFullscreen,
+    /// Placing the window with [`Window::position`].
+    WindowPosition,
+    /// Keeping the window above or below others with [`Window::window_level`].
+    WindowLevel,
+    /// Setting the window icon, e.g. with [`DefaultWindowIcon`](crate::DefaultWindowIcon).
+    WindowIcon,
+    /// Composing text with an input method editor, see [`Window::ime_enabled`].
+    Ime,
+    /// Image and URL cursors, which also need the `custom_cursor` feature.
+    CustomCursor,
+    /// Showing the window on all workspaces, see
+    /// [`WinitWindows::set_visible_on_all_workspaces`], which needs the `x11` feature on X11.
+    VisibleOnAllWorkspaces,
+    /// Taskbar or dock badges, see [`WinitWindows::set_badge`].
+    Badge,
+}
+
+/// How long a window keeps its [`InteractionState`] after the last resize or move, see
+/// [`WinitWindows::interaction_state`].
+pub const INTERACTION_SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Whether a window is being resized or moved, see [`WinitWindows::interaction_state`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InteractionState {
+    /// The window is neither being resized nor moved.
+    #[default]
+    Idle,
+    /// The window is being resized.
+    Resizing,
+    /// The window is being moved.
+    Moving,
+}
+
+/// A badge on a taskbar or dock entry, see [`WinitWindows::set_badge`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Badge {
+    /// No badge is shown.
+    #[default]
+    None,
+    /// A number is shown, e.g. the number of unread messages.
+    Count(u32),
+    /// A small icon is shown over the app icon, from `width * height` pixels of 8-bit RGBA data
+    /// in row-major order.
+    Icon {
+        /// The pixel data of the icon.
+        rgba: Vec<u8>,
+        /// The width of the icon in pixels.
+        width: u32,
+        /// The height of the icon in pixels.
+        height: u32,
+    },
+}
+
+/// Marks a splash window that is closed as soon as the window of
+/// [`main_window`](Self::main_window) has presented its first frame, or has been closed.
+///
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_window::{PrimaryWindow, WindowResolution};
+/// # use bevy_winit::SplashWindow;
+/// fn show_splash(mut commands: Commands, primary_window: Single<Entity, With<PrimaryWindow>>) {
+///     commands.spawn((
+///         SplashWindow::window("Loading", WindowResolution::new(480., 270.)),
+///         SplashWindow::new(*primary_window),
+///     ));
+/// }
+/// ```
+///
+/// Combine it with [`Window::visible_on_first_frame`] on the main window so it doesn't appear
+/// before it has content. Cameras still need to target the splash window to render to it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplashWindow {
+    /// The window entity whose first frame closes the splash window.
+    pub main_window: Entity,
+}
+
+impl SplashWindow {
+    /// Creates a splash window marker that waits for `main_window`.
+    pub fn new(main_window: Entity) -> Self {
+        Self { main_window }
+    }
+
+    /// Returns an undecorated, centered and always-on-top [`Window`] suited for a splash window.
+    pub fn window(title: impl Into<String>, resolution: WindowResolution) -> Window {
+        Window {
+            title: title.into(),
+            resolution,
+            position: WindowPosition::Centered(MonitorSelection::Current),
+            decorations: false,
+            resizable: false,
+            window_level: WindowLevel::AlwaysOnTop,
+            skip_taskbar: cfg!(target_os = "windows"),
+            visible_on_first_frame: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Marks a dialog-style window that blocks interaction with its [`parent`](Self::parent) window
+/// while it is open.
+///
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_window::{PrimaryWindow, Window};
+/// # use bevy_winit::ModalWindow;
+/// fn open_dialog(mut commands: Commands, primary_window: Single<Entity, With<PrimaryWindow>>) {
+///     commands.spawn((
+///         Window {
+///             title: "Unsaved changes".into(),
+///             ..Default::default()
+///         },
+///         ModalWindow::new(*primary_window),
+///     ));
+/// }
+/// ```
+///
+/// The parent is blocked once the modal window has been created, and unblocked when the modal
+/// window is closed or the component is removed.
+///
+/// ## Platform-specific
+///
+/// - **Windows:** The parent is disabled, so it doesn't receive any input.
+/// - **Others:** There is no native modality, so focus is handed back to the modal window
+///   whenever the parent gains it. The parent can still receive cursor and mouse button events
+///   while it is hovered, so input handling for the parent should check for an open modal window.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModalWindow {
+    /// The window entity that is blocked while the modal window is open.
+    pub parent: Entity,
+}
+
+impl ModalWindow {
+    /// Creates a modal window marker that blocks `parent`.
+    pub fn new(parent: Entity) -> Self {
+        Self { parent }
+    }
+}
+
+/// Enables or disables input to a window blocked by a [`ModalWindow`].
+pub(crate) fn set_modal_parent_enabled(winit_window: &WinitWindow, enabled: bool) {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows;
+        winit_window.set_enable(enabled);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    let _ = (winit_window, enabled);
+}
+
+/// How long the windows spawned with [`WinitWindows::flash_monitor_identifier`] are shown.
+pub const MONITOR_IDENTIFIER_DURATION: Duration = Duration::from_secs(2);
+
+/// Marks a window that briefly identifies a monitor, see
+/// [`WinitWindows::flash_monitor_identifier`]. The window is closed once
+/// [`close_at`](Self::close_at) has passed.
+///
+/// `winit` can't draw into windows, so cameras and UI should target the window to
+/// render the number into its content. The number is also the window title, which e.g. task
+/// switchers and screen readers show. In reactive [`UpdateMode`](crate::UpdateMode)s the window
+/// is closed on the first update after it expires.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorIdentifier {
+    /// The number identifying the monitor.
+    pub number: u32,
+    /// When the window is closed.
+    pub close_at: Instant,
+}
+
+impl MonitorIdentifier {
+    /// Creates a marker for a window showing `number` that closes after `duration`.
+    pub fn new(number: u32, duration: Duration) -> Self {
+        Self {
+            number,
+            close_at: Instant::now() + duration,
+        }
+    }
+}
+
+/// Changes to apply to a window at once with [`WinitWindows::update_window`].
+///
+/// Only the properties that have been set are changed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WindowUpdate {
+    pub(crate) title: Option<String>,
+    pub(crate) inner_size: Option<Vec2>,
+    pub(crate) decorations: Option<bool>,
+    pub(crate) window_level: Option<WindowLevel>,
+    pub(crate) position: Option<IVec2>,
+    pub(crate) visible: Option<bool>,
+}
+
+impl WindowUpdate {
+    /// Adds the changes of a later update, which take precedence.
+    fn merge(&mut self, later: Self) {
+        self.title = later.title.or(self.title.take());
+        self.inner_size = later.inner_size.or(self.inner_size);
+        self.decorations = later.decorations.or(self.decorations);
+        self.window_level = later.window_level.or(self.window_level);
+        self.position = later.position.or(self.position);
+        self.visible = later.visible.or(self.visible);
+    }
+
+    /// Changes the title of the window.
+    pub fn title(&mut self, title: impl Into<String>) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Requests a new inner size, in logical pixels.
+    pub fn inner_size(&mut self, size: Vec2) -> &mut Self {
+        self.inner_size = Some(size);
+        self
+    }
+
+    /// Shows or hides the decorations of the window.
+    ///
+    /// The size of the client area is kept unless a new [`inner_size`](Self::inner_size) is
+    /// requested as well.
+    pub fn decorations(&mut self, decorations: bool) -> &mut Self {
+        self.decorations = Some(decorations);
+        self
+    }
+
+    /// Changes the level of the window.
+    pub fn window_level(&mut self, window_level: WindowLevel) -> &mut Self {
+        self.window_level = Some(window_level);
+        self
+    }
+
+    /// Moves the top-left corner of the window, in physical pixels.
+    pub fn position(&mut self, position: IVec2) -> &mut Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Shows or hides the window.
+    pub fn visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = Some(visible);
+        self
+    }
+}
+
+/// Places a new [`Window`] next to an existing one, e.g. a tool window docked to the right of
+/// the main window.
+///
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_window::{PrimaryWindow, Window};
+/// # use bevy_winit::{ChildWindowBuilder, WinitWindows};
+/// fn open_tool_window(
+///     mut commands: Commands,
+///     primary_window: Single<Entity, With<PrimaryWindow>>,
+///     winit_windows: NonSend<WinitWindows>,
+/// ) {
+///     let window = ChildWindowBuilder::new(*primary_window)
+///         .with_gap(8)
+///         .build(&winit_windows, Window::default());
+///     commands.spawn(window);
+/// }
+/// ```
+///
+/// Cameras still need to target the new window to render to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChildWindowBuilder {
+    parent: Entity,
+    side: CompassQuadrant,
+    gap: u32,
+}
+
+impl ChildWindowBuilder {
+    /// Creates a builder that places windows to the right ([`CompassQuadrant::East`]) of
+    /// `parent`, without a gap.
+    pub fn new(parent: Entity) -> Self {
+        Self {
+            parent,
+            side: CompassQuadrant::East,
+            gap: 0,
+        }
+    }
+
+    /// Sets the side of the parent window to place windows on.
+    pub fn with_side(mut self, side: CompassQuadrant) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the gap between the parent window and placed windows, in physical pixels.
+    pub fn with_gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the [`Window::position`] of `window` so it is placed next to the parent window,
+    /// kept on the parent's monitor where possible.
+    ///
+    /// The position is left untouched if the parent has no window, or if its outer rectangle
+    /// is unavailable, see [`WinitWindows::outer_rect`].
+    pub fn build(&self, winit_windows: &WinitWindows, mut window: Window) -> Window {
+        let Some(parent_window) = winit_windows.get_window(self.parent) else {
+            return window;
+        };
+        let Some((parent_position, parent_size)) = winit_windows.outer_rect(self.parent) else {
+            return window;
+        };
+
+        // The window doesn't know its scale factor yet, so use the one of the parent.
+        let scale_factor = window
+            .resolution
+            .scale_factor_override()
+            .map_or_else(|| parent_window.scale_factor(), f64::from);
+        let size: (u32, u32) = LogicalSize::new(window.width(), window.height())
+            .to_physical::<u32>(scale_factor)
+            .into();
+        let size = UVec2::from(size).as_ivec2();
+        let parent_size = parent_size.as_ivec2();
+        let gap = self.gap as i32;
+
+        let mut position = match self.side {
+            CompassQuadrant::North => parent_position - IVec2::new(0, size.y + gap),
+            CompassQuadrant::East => parent_position + IVec2::new(parent_size.x + gap, 0),
+            CompassQuadrant::South => parent_position + IVec2::new(0, parent_size.y + gap),
+            CompassQuadrant::West => parent_position - IVec2::new(size.x + gap, 0),
+        };
+
+        if let Some(monitor) = parent_window.current_monitor() {
+            let monitor_position = monitor.position();
+            let monitor_position = IVec2::new(monitor_position.x, monitor_position.y);
+            let monitor_size = monitor.size();
+            let monitor_size = UVec2::new(monitor_size.width, monitor_size.height).as_ivec2();
+            let max_position = monitor_position + (monitor_size - size).max(IVec2::ZERO);
+            position = position.clamp(monitor_position, max_position);
+        }
+
+        window.position = WindowPosition::At(position);
+        window
+    }
+}
+
+/// The inner size of a window, as returned by [`WinitWindows::inner_size`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowSizes {
+    /// The inner size of the window in physical pixels.
+    pub physical: UVec2,
+    /// The inner size of the window in logical pixels.
+    pub logical: Vec2,
+    /// The scale factor used to convert between [`physical`](Self::physical) and
+    /// [`logical`](Self::logical).
+    pub scale_factor: f64,
+}
+
+/// Whether a window should be redrawn, see [`WinitWindows::request_redraws`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RedrawPoll {
+    /// The window should be redrawn now.
+    Now,
+    /// The window should be redrawn at the given time.
+    Deferred(Instant),
+    /// The window's redraw interval is too long to ever elapse.
+    Never,
+}
+
+/// How long [`WinitWindows::update_window`] holds back resizes at most while the window
+/// hasn't reached the requested size yet, e.g. because the window manager picked another size.
+pub const BATCHED_RESIZE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Resizes held back while a batch from [`WinitWindows::update_window`] settles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct HeldResize {
+    /// The physical inner size the batch requested, if it is known.
+    pub(crate) target: Option<PhysicalSize<u32>>,
+    /// The last physical size reported while held, which is applied once the hold times out.
+    pub(crate) physical_size: Option<PhysicalSize<u32>>,
+    /// When the hold times out.
+    pub(crate) until: Instant,
+}
+
+impl HeldResize {
+    pub(crate) fn new(target: Option<PhysicalSize<u32>>, now: Instant) -> Self {
+        Self {
+            target,
+            physical_size: None,
+            until: now + BATCHED_RESIZE_TIMEOUT,
+        }
+    }
+}
+
+/// A scale factor reported by `winit` that hasn't been applied to the [`Window`] yet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PendingScaleFactor {
+    pub(crate) scale_factor: f64,
+    /// The last physical size reported while the scale factor was pending, which is applied
+    /// together with it.
+    pub(crate) physical_size: Option<PhysicalSize<u32>>,
+    /// The number of consecutive updates the window spent mostly on a monitor with this scale
+    /// factor.
+    settled_frames: u32,
+}
+
+impl PendingScaleFactor {
+    pub(crate) fn new(scale_factor: f64) -> Self {
+        Self {
+            scale_factor,
+            physical_size: None,
+            settled_frames: 0,
+        }
+    }
+
+    /// Records an update where the monitor containing most of the window has the given scale
+    /// factor, or `None` if that monitor is unknown. Returns whether the pending scale factor
+    /// should be applied now.
+    pub(crate) fn settle(
+        &mut self,
+        majority_scale_factor: Option<f64>,
+        settle_frames: u32,
+    ) -> bool {
+        let Some(majority_scale_factor) = majority_scale_factor else {
+            return true;
+        };
+        if (majority_scale_factor - self.scale_factor).abs() < 1e-6 {
+            self.settled_frames += 1;
+        } else {
+            self.settled_frames = 0;
+        }
+        self.settled_frames >= settle_frames
+    }
+}
+
+/// A set of regions of a window that capture the cursor, see [`WinitWindows::set_hittest_mask`].
+#[derive(Debug)]
+pub(crate) struct HittestMask {
+    regions: Vec<Vec<Vec2>>,
+    /// The last known logical cursor position.
+    cursor_position: Option<Vec2>,
+    /// The logical cursor position last reported by window events.
+    ///
+    /// The window stops receiving cursor events while the cursor passes through, so this goes
+    /// stale and only replaces the estimated position when it changes.
+    reported_cursor_position: Option<Vec2>,
+    /// Whether hit-testing is currently enabled for the window.
+    hittest: bool,
+}
+
+impl HittestMask {
+    pub(crate) fn new(regions: Vec<Vec<Vec2>>) -> Self {
+        Self {
+            regions,
+            cursor_position: None,
+            reported_cursor_position: None,
+            hittest: true,
+        }
+    }
+
+    /// Takes the logical cursor position reported by window events over the estimated one if it
+    /// changed since the last report.
+    fn report_cursor_position(&mut self, reported: Option<Vec2>) {
+        if reported.is_some() && reported != self.reported_cursor_position {
+            self.cursor_position = reported;
+        }
+        self.reported_cursor_position = reported;
+    }
+}
+
+/// Returns whether `point` is inside `polygon`, using the even-odd rule.
+fn polygon_contains(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    let mut previous = match polygon.last() {
+        Some(last) => *last,
+        None => return false,
+    };
+    for &current in polygon {
+        if (current.y > point.y) != (previous.y > point.y)
+            && point.x
+                < (previous.x - current.x) * (point.y - current.y) / (previous.y - current.y)
+                    + current.x
+        {
+            inside = !inside;
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// Information about the display a window was created on.
+///
+/// Inserted on window entities when their window is created, e.g. to show the resolution,
+/// monitor and refresh rate in a debug overlay.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct DisplayInfo {
+    /// The physical resolution of the window.
+    pub window_physical_resolution: (u32, u32),
+    /// The logical resolution of the window.
+    pub window_logical_resolution: (f32, f32),
+    /// The name of the monitor the window is on, if known.
+    pub monitor_name: Option<String>,
+    /// The scale factor of the monitor the window is on, if known.
+    pub scale_factor: Option<f64>,
+    /// The refresh rate of the monitor the window is on, if known.
+    pub refresh_rate_millihertz: Option<u32>,
+}
+
+impl DisplayInfo {
+    pub(crate) fn new(window: &Window, monitor: Option<&MonitorHandle>) -> Self {
+        Self {
+            window_physical_resolution: (
+                window.resolution.physical_width(),
+                window.resolution.physical_height(),
+            ),
+            window_logical_resolution: (window.resolution.width(), window.resolution.height()),
+            monitor_name: monitor.and_then(MonitorHandle::name),
+            scale_factor: monitor.map(MonitorHandle::scale_factor),
+            refresh_rate_millihertz: monitor.and_then(MonitorHandle::refresh_rate_millihertz),
+        }
+    }
+}
+
+impl core::fmt::Display for DisplayInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Display information:")?;
+        write!(
+            f,
+            "  Window physical resolution: {}x{}",
+            self.window_physical_resolution.0, self.window_physical_resolution.1
+        )?;
+        write!(
+            f,
+            "  Window logical resolution: {}x{}",
+            self.window_logical_resolution.0, self.window_logical_resolution.1
+        )?;
+        write!(
+            f,
+            "  Monitor name: {}",
+            self.monitor_name.as_deref().unwrap_or("")
+        )?;
+        write!(f, "  Scale factor: {}", self.scale_factor.unwrap_or(0.))?;
+        let millihertz = self.refresh_rate_millihertz.unwrap_or(0);
+        let hertz = millihertz / 1000;
+        let extra_millihertz = millihertz % 1000;
+        write!(f, "  Refresh rate (Hz): {}.{:03}", hertz, extra_millihertz)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VirtualMonitor;
+
+    #[test]
+    fn actual_window_mode_follows_the_fullscreen_state() {
+        let borderless = Fullscreen::Borderless(None);
+        let primary = || MonitorSelection::Primary;
+        assert_eq!(actual_window_mode(WindowMode::Windowed, None), None);
+        assert_eq!(
+            actual_window_mode(
+                WindowMode::BorderlessFullscreen(primary()),
+                Some(&borderless)
+            ),
+            None
+        );
+        assert_eq!(
+            actual_window_mode(WindowMode::Fullscreen(primary()), Some(&borderless)),
+            Some(WindowMode::BorderlessFullscreen(MonitorSelection::Current))
+        );
+        assert_eq!(
+            actual_window_mode(WindowMode::BorderlessFullscreen(primary()), None),
+            Some(WindowMode::Windowed)
+        );
+    }
+
+    #[test]
+    fn fullscreen_without_monitors_falls_back_to_windowed() {
+        let fallback = |mode| fullscreen_monitor_fallback(mode, None, None).0;
+        assert_eq!(
+            fallback(WindowMode::Fullscreen(MonitorSelection::Index(3))),
+            WindowMode::Windowed
+        );
+        assert_eq!(
+            fallback(WindowMode::SizedFullscreen(MonitorSelection::Current)),
+            WindowMode::Windowed
+        );
+        assert_eq!(
+            fallback(WindowMode::BorderlessFullscreen(MonitorSelection::Primary)),
+            WindowMode::BorderlessFullscreen(MonitorSelection::Primary)
+        );
+    }
+
+    #[test]
+    fn hittest_mask_ignores_stale_cursor_positions() {
+        let mut mask = HittestMask::new(Vec::new());
+        mask.report_cursor_position(Some(Vec2::new(10.0, 10.0)));
+        assert_eq!(mask.cursor_position, Some(Vec2::new(10.0, 10.0)));
+
+        // The cursor passes through and the estimate moves, while the window keeps reporting
+        // where the cursor was last seen.
+        mask.cursor_position = Some(Vec2::new(50.0, 10.0));
+        mask.report_cursor_position(Some(Vec2::new(10.0, 10.0)));
+        assert_eq!(mask.cursor_position, Some(Vec2::new(50.0, 10.0)));
+
+        mask.report_cursor_position(None);
+        assert_eq!(mask.cursor_position, Some(Vec2::new(50.0, 10.0)));
+        mask.report_cursor_position(Some(Vec2::new(12.0, 10.0)));
+        assert_eq!(mask.cursor_position, Some(Vec2::new(12.0, 10.0)));
+    }
+
+    #[test]
+    fn confined_cursor_position_clamps_to_the_region() {
+        let region = Some(Rect::new(10.0, 10.0, 100.0, 50.0));
+        let confined =
+            |position| confined_cursor_position(CursorGrabMode::Confined, region, position);
+        assert_eq!(confined(Vec2::new(50.0, 20.0)), None);
+        assert_eq!(confined(Vec2::new(5.0, 20.0)), Some(Vec2::new(10.0, 20.0)));
+        assert_eq!(
+            confined(Vec2::new(120.0, 60.0)),
+            Some(Vec2::new(100.0, 50.0))
+        );
+        assert_eq!(
+            confined_cursor_position(CursorGrabMode::Locked, region, Vec2::ZERO),
+            None
+        );
+        assert_eq!(
+            confined_cursor_position(CursorGrabMode::Confined, None, Vec2::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn fitting_videomode_prefers_the_closest_size() {
+        let target = UVec2::new(1920, 1080);
+        let key = |size: UVec2, refresh, bit_depth| {
+            fitting_videomode_key(size, refresh, bit_depth, target, None)
+        };
+        let exact = key(target, 60_000, 32);
+        assert!(exact < key(UVec2::new(640, 480), 60_000, 32));
+        assert!(exact < key(UVec2::new(3840, 2160), 144_000, 32));
+        // Among equal sizes, the higher refresh rate wins.
+        assert!(key(target, 144_000, 32) < exact);
+
+        let deep = fitting_videomode_key(UVec2::new(1280, 720), 60_000, 30, target, Some(30));
+        assert!(deep < fitting_videomode_key(target, 60_000, 24, target, Some(30)));
+    }
+
+    #[test]
+    fn resize_bursts_coalesce_into_a_single_redraw() {
+        let mut winit_windows = WinitWindows::default();
+        let entity = Entity::from_raw(0);
+        let delay = Some(Duration::from_millis(50));
+        let start = Instant::now();
+
+        let mut redraws = 0;
+        for step in 0..20 {
+            let now = start + Duration::from_millis(step * 10);
+            winit_windows.record_resize(entity, now);
+            if winit_windows.poll_redraw(entity, now, None, delay) == RedrawPoll::Now {
+                redraws += 1;
+            }
+        }
+        assert_eq!(redraws, 0);
+
+        let settled = start + Duration::from_millis(190 + 50);
+        assert_eq!(
+            winit_windows.poll_redraw(entity, settled, None, delay),
+            RedrawPoll::Now
+        );
+        assert!(!winit_windows.is_resizing(entity));
+    }
+
+    #[test]
+    fn settled_resizes_are_redrawn_without_a_redraw_request() {
+        let mut winit_windows = WinitWindows::default();
+        let entity = Entity::from_raw(0);
+        let delay = Duration::from_millis(50);
+        let start = Instant::now();
+        let settings = |_| (None, Some(delay));
+
+        winit_windows.record_resize(entity, start);
+        assert_eq!(
+            winit_windows.request_redraws(start, false, settings),
+            Some(start + delay)
+        );
+        assert!(winit_windows.is_resizing(entity));
+
+        assert_eq!(
+            winit_windows.request_redraws(start + delay, false, settings),
+            None
+        );
+        assert!(!winit_windows.is_resizing(entity));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn supported_features_follow_the_linux_backend() {
+        let mut winit_windows = WinitWindows::default();
+        assert!(!winit_windows.supports(WindowFeature::Ime));
+
+        winit_windows.backend = Some(WinitBackend::X11);
+        assert!(winit_windows.supports(WindowFeature::ExclusiveFullscreen));
+        assert!(!winit_windows.supports(WindowFeature::CursorLock));
+
+        winit_windows.backend = Some(WinitBackend::Wayland);
+        assert!(!winit_windows.supports(WindowFeature::ExclusiveFullscreen));
+        assert!(winit_windows.supports(WindowFeature::CursorLock));
+    }
+
+    #[test]
+    fn idle_duration_counts_from_the_last_input() {
+        let mut winit_windows = WinitWindows::default();
+        let entity = Entity::from_raw(0);
+        let start = Instant::now();
+        assert_eq!(winit_windows.idle_duration_at(entity, start), None);
+
+        winit_windows.record_input(entity, start);
+        assert_eq!(
+            winit_windows.idle_duration_at(entity, start + Duration::from_secs(60)),
+            Some(Duration::from_secs(60))
+        );
+
+        winit_windows.record_input(entity, start + Duration::from_secs(50));
+        assert_eq!(
+            winit_windows.idle_duration_at(entity, start + Duration::from_secs(60)),
+            Some(Duration::from_secs(10))
+        );
+
+        winit_windows.remove_window(entity);
+        assert_eq!(winit_windows.idle_duration_at(entity, start), None);
+    }
+
+    #[test]
+    fn interaction_state_settles_after_the_last_event() {
+        let mut winit_windows = WinitWindows::default();
+        let entity = Entity::from_raw(0);
+        let start = Instant::now();
+        assert_eq!(
+            winit_windows.interaction_state_at(entity, start),
+            InteractionState::Idle
+        );
+
+        winit_windows.record_interaction(entity, InteractionState::Moving, start);
+        assert_eq!(
+            winit_windows.interaction_state_at(entity, start),
+            InteractionState::Moving
+        );
+
+        // Resizing from the left edge also moves the window.
+        let resize = start + Duration::from_millis(50);
+        winit_windows.record_interaction(entity, InteractionState::Resizing, resize);
+        winit_windows.record_interaction(entity, InteractionState::Moving, resize);
+        assert_eq!(
+            winit_windows.interaction_state_at(entity, resize),
+            InteractionState::Resizing
+        );
+
+        let settled = resize + INTERACTION_SETTLE_TIME;
+        assert_eq!(
+            winit_windows.interaction_state_at(entity, settled),
+            InteractionState::Idle
+        );
+    }
+
+    #[test]
+    fn focused_windows_move_to_the_front_of_the_z_order() {
+        let mut winit_windows = WinitWindows::default();
+        let [a, b, c] = [0, 1, 2].map(Entity::from_raw);
+        for entity in [a, b, c] {
+            winit_windows.raise_in_z_order(entity);
+        }
+        assert_eq!(winit_windows.z_ordered_entities(), [c, b, a]);
+
+        winit_windows.raise_in_z_order(a);
+        assert_eq!(winit_windows.z_ordered_entities(), [a, c, b]);
+
+        winit_windows.remove_window(c);
+        assert_eq!(winit_windows.z_ordered_entities(), [a, b]);
+    }
+
+    #[test]
+    fn batched_resizes_are_held_until_the_requested_size() {
+        let mut winit_windows = WinitWindows::default();
+        let entity = Entity::from_raw(0);
+        let target = PhysicalSize::new(320, 240);
+        assert!(!winit_windows.hold_resize(entity, PhysicalSize::new(300, 200)));
+
+        winit_windows
+            .held_resizes
+            .insert(entity, HeldResize::new(Some(target), Instant::now()));
+        assert!(winit_windows.hold_resize(entity, PhysicalSize::new(300, 200)));
+        assert_eq!(
+            winit_windows.held_resizes[&entity].physical_size,
+            Some(PhysicalSize::new(300, 200))
+        );
+        assert!(!winit_windows.hold_resize(entity, target));
+        assert!(winit_windows.held_resizes.is_empty());
+    }
+
+    #[test]
+    fn only_pending_closes_can_be_confirmed() {
+        let mut winit_windows = WinitWindows::default();
+        let entity = Entity::from_raw(0);
+        assert!(!winit_windows.confirm_close(entity));
+
+        winit_windows.pending_closes.insert(entity);
+        assert!(winit_windows.cancel_close(entity));
+        assert!(!winit_windows.is_close_pending(entity));
+        assert!(!winit_windows.confirm_close(entity));
+        assert!(winit_windows.confirmed_closes.is_empty());
+
+        winit_windows.pending_closes.insert(entity);
+        assert!(winit_windows.confirm_close(entity));
+        assert!(winit_windows.confirmed_closes.contains(&entity));
+    }
+
+    #[test]
+    fn restoring_a_minimized_window_is_reported_as_minimized() {
+        use ActivationReason::*;
+
+        assert_eq!(
+            activation_reason(false, Some((true, Focus)), true, true),
+            Minimized
+        );
+        assert_eq!(
+            activation_reason(true, Some((false, Minimized)), false, false),
+            Minimized
+        );
+        assert_eq!(
+            activation_reason(false, Some((true, Focus)), false, true),
+            Occluded
+        );
+        assert_eq!(
+            activation_reason(false, Some((true, Focus)), false, false),
+            Focus
+        );
+        assert_eq!(
+            activation_reason(true, Some((false, Occluded)), false, false),
+            Focus
+        );
+    }
+
+    #[test]
+    fn virtual_monitor_positions_windows_without_monitors() {
+        let mut monitors = WinitMonitors::default();
+        let resolution = WindowResolution::new(800., 600.);
+        let position = |position: &WindowPosition, monitors: &WinitMonitors| {
+            winit_window_position(position, &resolution, true, monitors, None, None)
+        };
+        let centered = WindowPosition::Centered(MonitorSelection::Primary);
+        assert_eq!(position(&centered, &monitors), None);
+
+        monitors.virtual_monitor = Some(VirtualMonitor {
+            position: IVec2::new(100, 0),
+            size: UVec2::new(1920, 1080),
+            scale_factor: 1.5,
+        });
+        assert_eq!(
+            position(&centered, &monitors),
+            Some(PhysicalPosition::new(460, 90))
+        );
+        assert_eq!(
+            position(&WindowPosition::At(IVec2::new(5000, 100)), &monitors),
+            Some(PhysicalPosition::new(820, 100))
+        );
+    }
+
+    #[test]
+    fn anchored_positions_keep_their_margin_from_the_monitor_edges() {
+        let monitors = WinitMonitors {
+            virtual_monitor: Some(VirtualMonitor {
+                position: IVec2::new(100, 0),
+                size: UVec2::new(1920, 1080),
+                scale_factor: 1.5,
+            }),
+            ..Default::default()
+        };
+        let resolution = WindowResolution::new(800., 600.);
+        let anchored = |anchor| WindowPosition::AnchoredToMonitor {
+            monitor: MonitorSelection::Primary,
+            anchor,
+            margin: IVec2::new(10, 20),
+        };
+        let position = |anchor| {
+            winit_window_position(&anchored(anchor), &resolution, false, &monitors, None, None)
+        };
+
+        assert_eq!(
+            position(MonitorAnchor::TopRight),
+            Some(PhysicalPosition::new(810, 20))
+        );
+        assert_eq!(
+            position(MonitorAnchor::Bottom),
+            Some(PhysicalPosition::new(460, 160))
+        );
+        assert_eq!(
+            position(MonitorAnchor::Center),
+            Some(PhysicalPosition::new(460, 90))
+        );
+    }
+
+    #[test]
+    fn infinite_max_size_clears_maximum() {
+        let finite = WindowResizeConstraints {
+            max_width: 800.,
+            max_height: 600.,
+            ..Default::default()
+        };
+        let (_, max_inner_size) = inner_size_limits(&finite);
+        assert_eq!(max_inner_size, Some(LogicalSize::new(800., 600.)));
+
+        let infinite = WindowResizeConstraints {
+            max_height: f32::INFINITY,
+            ..finite
+        };
+        let (_, max_inner_size) = inner_size_limits(&infinite);
+        assert_eq!(max_inner_size, None);
+    }
+
+    #[test]
+    fn scale_factor_override_takes_precedence() {
+        let resolution = WindowResolution::new(800., 600.);
+        assert_eq!(resolve_scale_factor(&resolution, None), 1.0);
+
+        let resolution = resolution.with_scale_factor_override(2.0);
+        assert_eq!(resolve_scale_factor(&resolution, None), 2.0);
+    }
+
+    #[test]
+    fn aspect_correction_keeps_dragged_dimension() {
+        let previous = Vec2::new(1600., 900.);
+        assert_eq!(
+            aspect_corrected_size(previous, Vec2::new(1600., 1000.), 16. / 9.),
+            Some(Vec2::new(1000. * 16. / 9., 1000.))
+        );
+        assert_eq!(
+            aspect_corrected_size(previous, Vec2::new(1920., 900.), 16. / 9.),
+            Some(Vec2::new(1920., 1080.))
+        );
+        assert_eq!(
+            aspect_corrected_size(previous, Vec2::new(1921., 1080.), 16. / 9.),
+            None
+        );
+    }
+
+    #[test]
+    fn pending_scale_factor_waits_for_consecutive_frames() {
+        let mut pending = PendingScaleFactor::new(2.0);
+        assert!(!pending.settle(Some(2.0), 2));
+        assert!(!pending.settle(Some(1.0), 2));
+        assert!(!pending.settle(Some(2.0), 2));
+        assert!(pending.settle(Some(2.0), 2));
+
+        let mut pending = PendingScaleFactor::new(2.0);
+        assert!(pending.settle(None, 2));
+    }
+
+    #[test]
+    fn polygon_contains_uses_even_odd_rule() {
+        let triangle = [Vec2::new(0., 0.), Vec2::new(100., 0.), Vec2::new(0., 100.)];
+        assert!(polygon_contains(&triangle, Vec2::new(10., 10.)));
+        assert!(!polygon_contains(&triangle, Vec2::new(90., 90.)));
+        assert!(!polygon_contains(&[], Vec2::ZERO));
     }
 }
@@ -0,0 +1,207 @@
+//! Requests to the X server for window features that `winit` doesn't expose.
+//!
+//! `winit` doesn't share its own connection, so [`connection`] opens a separate one, which is
+//! kept for the lifetime of the app.
+
+use alloc::vec::Vec;
+use bevy_math::IVec2;
+use std::sync::OnceLock;
+use x11rb::{
+    connection::Connection,
+    protocol::randr::ConnectionExt as _,
+    protocol::xproto::{
+        Atom, AtomEnum, ClientMessageEvent, ConnectionExt as _, EventMask, PropMode,
+    },
+    rust_connection::RustConnection,
+    wrapper::ConnectionExt as _,
+};
+
+/// Returns the shared connection to the X server, connecting on first use.
+///
+/// Returns `None` if the connection failed, which isn't retried.
+pub(crate) fn connection() -> Option<&'static X11Connection> {
+    static CONNECTION: OnceLock<Option<X11Connection>> = OnceLock::new();
+    CONNECTION.get_or_init(X11Connection::connect).as_ref()
+}
+
+/// A connection to the X server, see [`connection`].
+#[derive(Debug)]
+pub(crate) struct X11Connection {
+    connection: RustConnection,
+    /// The index of the default screen.
+    screen: usize,
+}
+
+impl X11Connection {
+    /// Connects to the display in the `DISPLAY` environment variable, like `winit` does.
+    fn connect() -> Option<Self> {
+        let (connection, screen) = x11rb::connect(None).ok()?;
+        Some(Self { connection, screen })
+    }
+
+    fn atom(&self, name: &[u8]) -> Option<Atom> {
+        Some(
+            self.connection
+                .intern_atom(false, name)
+                .ok()?
+                .reply()
+                .ok()?
+                .atom,
+        )
+    }
+
+    /// Returns the physical cursor position relative to the top-left corner of `window`, or
+    /// `None` if the cursor is on another screen.
+    pub(crate) fn cursor_position(&self, window: u32) -> Option<IVec2> {
+        let reply = self.connection.query_pointer(window).ok()?.reply().ok()?;
+        reply
+            .same_screen
+            .then(|| IVec2::new(reply.win_x.into(), reply.win_y.into()))
+    }
+
+    /// Moves `window` to all desktops, or back to the current desktop.
+    ///
+    /// The `_NET_WM_DESKTOP` property is set for windows that aren't mapped yet, and the window
+    /// manager is asked to move mapped windows, following the EWMH.
+    pub(crate) fn set_visible_on_all_workspaces(&self, window: u32, visible: bool) -> bool {
+        /// The `_NET_WM_DESKTOP` value for windows shown on all desktops.
+        const ALL_DESKTOPS: u32 = 0xFFFF_FFFF;
+
+        let set_desktop = || -> Option<()> {
+            let root = self.connection.setup().roots.get(self.screen)?.root;
+            let desktop_atom = self.atom(b"_NET_WM_DESKTOP")?;
+            let desktop = if visible {
+                ALL_DESKTOPS
+            } else {
+                let current_desktop = self.atom(b"_NET_CURRENT_DESKTOP")?;
+                self.connection
+                    .get_property(false, root, current_desktop, AtomEnum::CARDINAL, 0, 1)
+                    .ok()?
+                    .reply()
+                    .ok()?
+                    .value32()?
+                    .next()?
+            };
+            self.connection
+                .change_property32(
+                    PropMode::REPLACE,
+                    window,
+                    desktop_atom,
+                    AtomEnum::CARDINAL,
+                    &[desktop],
+                )
+                .ok()?;
+            // The second field marks the request as coming from a normal application.
+            let event = ClientMessageEvent::new(32, window, desktop_atom, [desktop, 1, 0, 0, 0]);
+            self.connection
+                .send_event(
+                    false,
+                    root,
+                    EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+                    event,
+                )
+                .ok()?;
+            self.connection.flush().ok()
+        };
+        set_desktop().is_some()
+    }
+
+    /// Returns the gamma ramp of a `RandR` CRTC.
+    pub(crate) fn gamma_ramp(&self, crtc: u32) -> Option<GammaRamp> {
+        let reply = self
+            .connection
+            .randr_get_crtc_gamma(crtc)
+            .ok()?
+            .reply()
+            .ok()?;
+        Some(GammaRamp {
+            red: reply.red,
+            green: reply.green,
+            blue: reply.blue,
+        })
+    }
+
+    /// Replaces the gamma ramp of a `RandR` CRTC.
+    pub(crate) fn set_gamma_ramp(&self, crtc: u32, ramp: &GammaRamp) -> bool {
+        self.connection
+            .randr_set_crtc_gamma(crtc, &ramp.red, &ramp.green, &ramp.blue)
+            .is_ok_and(|cookie| cookie.check().is_ok())
+    }
+
+    /// Sets the `_NET_WM_WINDOW_OPACITY` property of `window`, or removes it for a fully opaque
+    /// window.
+    pub(crate) fn set_opacity(&self, window: u32, opacity: f32) -> bool {
+        let set_opacity = || -> Option<()> {
+            let atom = self.atom(b"_NET_WM_WINDOW_OPACITY")?;
+            if opacity >= 1.0 {
+                self.connection.delete_property(window, atom).ok()?;
+            } else {
+                // The property scales the opacity to the full range of a 32-bit cardinal.
+                let value = (f64::from(opacity) * f64::from(u32::MAX)).round() as u32;
+                self.connection
+                    .change_property32(
+                        PropMode::REPLACE,
+                        window,
+                        atom,
+                        AtomEnum::CARDINAL,
+                        &[value],
+                    )
+                    .ok()?;
+            }
+            self.connection.flush().ok()
+        };
+        set_opacity().is_some()
+    }
+}
+
+/// The gamma ramp of a `RandR` CRTC, mapping input intensities to output intensities for each
+/// channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GammaRamp {
+    pub(crate) red: Vec<u16>,
+    pub(crate) green: Vec<u16>,
+    pub(crate) blue: Vec<u16>,
+}
+
+impl GammaRamp {
+    /// Applies `gamma` on top of this ramp, brightening it for values above `1.0` and darkening
+    /// it for values below.
+    pub(crate) fn with_gamma(&self, gamma: f32) -> Self {
+        let exponent = 1.0 / gamma;
+        let adjust = |channel: &[u16]| {
+            channel
+                .iter()
+                .map(|&value| {
+                    let normalized = f32::from(value) / f32::from(u16::MAX);
+                    (bevy_math::ops::powf(normalized, exponent) * f32::from(u16::MAX)).round()
+                        as u16
+                })
+                .collect()
+        };
+        Self {
+            red: adjust(&self.red),
+            green: adjust(&self.green),
+            blue: adjust(&self.blue),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gamma_is_applied_on_top_of_the_ramp() {
+        let ramp = GammaRamp {
+            red: alloc::vec![0, 16384, 32768, u16::MAX],
+            green: alloc::vec![0, 32768, u16::MAX],
+            blue: alloc::vec![0, u16::MAX],
+        };
+        assert_eq!(ramp.with_gamma(1.0), ramp);
+
+        let brighter = ramp.with_gamma(2.0);
+        assert!(brighter.red[1] > ramp.red[1] && brighter.red[2] > ramp.red[2]);
+        assert_eq!((brighter.red[0], brighter.red[3]), (0, u16::MAX));
+        assert!(ramp.with_gamma(0.5).green[1] < ramp.green[1]);
+    }
+}
@@ -58,6 +58,9 @@ fn setup_cursor_icon(
             // The hotspot is the point in the cursor image that will be
             // positioned at the mouse cursor's position.
             hotspot: (0, 0),
+            // Whether to scale the image and hotspot by the window's scale
+            // factor, keeping the cursor's size on HiDPI monitors.
+            scale_with_window: false,
         })),
         animation_config,
     ));
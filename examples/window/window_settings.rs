@@ -91,7 +91,8 @@ fn switch_level(input: Res<ButtonInput<KeyCode>>, mut window: Single<&mut Window
         window.window_level = match window.window_level {
             WindowLevel::AlwaysOnBottom => WindowLevel::Normal,
             WindowLevel::Normal => WindowLevel::AlwaysOnTop,
-            WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnBottom,
+            WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTopWhenFocused,
+            WindowLevel::AlwaysOnTopWhenFocused => WindowLevel::AlwaysOnBottom,
         };
         info!("WINDOW_LEVEL: {:?}", window.window_level);
     }